@@ -0,0 +1,301 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Result, parse_macro_input};
+
+/// Derives `urlap::FormStruct` for a struct with named fields.
+///
+/// Every field is exposed under its own name, unless configured otherwise
+/// through the `#[form(...)]` attribute:
+///
+/// - `#[form(rename = "name")]`: Exposes the field under a different name
+/// - `#[form(skip)]`: Leaves the field out of `get` and `set`
+/// - `#[form(nested)]`: Delegates field paths such as `shipping.city` or
+///   `items[2].qty` to a nested `FormStruct`, or a list of them
+/// - `#[form(list)]`: Exposes a `Vec` field as a list, to be edited through
+///   a `urlap::FieldArray`. Lists of `#[form(nested)]` rows are exposed as
+///   lists without this attribute
+/// - `#[form(parse = "path")]`: Parses the field value using a
+///   `fn(&str) -> Result<T, E>` where `E: Display`, instead of
+///   `urlap::FromFieldValue`
+/// - `#[form(format = "path")]`: Formats the field value using a
+///   `fn(&T) -> String` instead of `urlap::IntoFieldValue`
+///
+/// Fields without hooks must implement both `urlap::FromFieldValue` and
+/// `urlap::IntoFieldValue`.
+#[proc_macro_derive(FormStruct, attributes(form))]
+pub fn derive_form_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct FieldOpts {
+    rename: Option<String>,
+    skip: bool,
+    nested: bool,
+    list: bool,
+    parse: Option<Path>,
+    format: Option<Path>,
+}
+
+impl FieldOpts {
+    fn from_field(field: &syn::Field) -> Result<Self> {
+        let mut opts = FieldOpts::default();
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("form"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    opts.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("skip") {
+                    opts.skip = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("nested") {
+                    opts.nested = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("list") {
+                    opts.list = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("parse") {
+                    opts.parse = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("format") {
+                    opts.format = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    return Ok(());
+                }
+
+                Err(meta.error("unsupported `form` attribute"))
+            })?;
+        }
+
+        Ok(opts)
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "`FormStruct` can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "`FormStruct` can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut get_arms = Vec::new();
+    let mut set_arms = Vec::new();
+    let mut nested_get = Vec::new();
+    let mut nested_set = Vec::new();
+    let mut list_arms = Vec::new();
+    let mut list_mut_arms = Vec::new();
+    let mut nested_list = Vec::new();
+    let mut nested_list_mut = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in fields {
+        let opts = FieldOpts::from_field(field)?;
+
+        if opts.skip {
+            continue;
+        }
+
+        let field_ident: &Ident = field.ident.as_ref().expect("named field");
+        let name = opts.rename.unwrap_or_else(|| field_ident.to_string());
+
+        if opts.nested {
+            nested_get.push(delegate(
+                &name,
+                quote! { ::urlap::NestedFields::get_nested(&self.#field_ident, rest) },
+            ));
+            nested_set.push(delegate(
+                &name,
+                quote! { ::urlap::NestedFields::set_nested(&mut self.#field_ident, rest, value) },
+            ));
+            nested_list.push(delegate(
+                &name,
+                quote! { ::urlap::NestedFields::list_nested(&self.#field_ident, rest) },
+            ));
+            nested_list_mut.push(delegate(
+                &name,
+                quote! { ::urlap::NestedFields::list_nested_mut(&mut self.#field_ident, rest) },
+            ));
+
+            field_names.push(quote! {
+                names.extend(::urlap::NestedFields::nested_field_names(&self.#field_ident, #name));
+            });
+
+            list_arms.push(quote! {
+                #name => ::urlap::NestedFields::as_list(&self.#field_ident),
+            });
+
+            list_mut_arms.push(quote! {
+                #name => ::urlap::NestedFields::as_list_mut(&mut self.#field_ident),
+            });
+
+            continue;
+        }
+
+        field_names.push(quote! {
+            names.push(::std::string::ToString::to_string(#name));
+        });
+
+        if opts.list {
+            list_arms.push(quote! {
+                #name => ::std::option::Option::Some(&self.#field_ident),
+            });
+
+            list_mut_arms.push(quote! {
+                #name => ::std::option::Option::Some(&mut self.#field_ident),
+            });
+        }
+
+        let get = match &opts.format {
+            Some(format) => quote! {
+                ::urlap::FieldValue::Text(#format(&self.#field_ident))
+            },
+            None => quote! {
+                ::urlap::IntoFieldValue::to_field_value(&self.#field_ident)
+            },
+        };
+
+        let parse = match &opts.parse {
+            Some(parse) => quote! {
+                #parse(&::std::string::ToString::to_string(&value)).map_err(|err| {
+                    ::urlap::FieldParseError::new("parse", ::std::string::ToString::to_string(&err))
+                })?
+            },
+            None => quote! {
+                ::urlap::FromFieldValue::from_field_value(value)?
+            },
+        };
+
+        get_arms.push(quote! {
+            #name => ::std::option::Option::Some(#get),
+        });
+
+        set_arms.push(quote! {
+            #name => {
+                self.#field_ident = #parse;
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::urlap::FormStruct for #ident #ty_generics #where_clause {
+            fn get(&self, name: &str) -> ::std::option::Option<::std::string::String> {
+                ::urlap::FormStruct::get_value(self, name)
+                    .map(|value| ::std::string::ToString::to_string(&value))
+            }
+
+            fn set(&mut self, name: &str, value: &str) {
+                let _ = ::urlap::FormStruct::try_set(self, name, value);
+            }
+
+            fn try_set(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> ::std::result::Result<(), ::urlap::FieldParseError> {
+                ::urlap::FormStruct::set_value(
+                    self,
+                    name,
+                    ::urlap::FieldValue::Text(::std::string::ToString::to_string(value)),
+                )
+            }
+
+            fn get_value(&self, name: &str) -> ::std::option::Option<::urlap::FieldValue> {
+                #(#nested_get)*
+
+                match name {
+                    #(#get_arms)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn set_value(
+                &mut self,
+                name: &str,
+                value: ::urlap::FieldValue,
+            ) -> ::std::result::Result<(), ::urlap::FieldParseError> {
+                #(#nested_set)*
+
+                match name {
+                    #(#set_arms)*
+                    _ => {}
+                }
+
+                ::std::result::Result::Ok(())
+            }
+
+            fn field_names(&self) -> ::std::vec::Vec<::std::string::String> {
+                let mut names = ::std::vec::Vec::new();
+                #(#field_names)*
+                names
+            }
+
+            fn list(&self, name: &str) -> ::std::option::Option<&dyn ::urlap::FieldList> {
+                #(#nested_list)*
+
+                match name {
+                    #(#list_arms)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn list_mut(
+                &mut self,
+                name: &str,
+            ) -> ::std::option::Option<&mut dyn ::urlap::FieldList> {
+                #(#nested_list_mut)*
+
+                match name {
+                    #(#list_mut_arms)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Returns the outcome of `call` when `name` is a path into the nested field
+/// `field`, binding the remainder of the path to `rest`
+fn delegate(field: &str, call: TokenStream2) -> TokenStream2 {
+    quote! {
+        match name.strip_prefix(#field) {
+            ::std::option::Option::Some(rest) if rest.starts_with(['.', '[']) => {
+                return #call;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,616 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use leptos::prelude::{Memo, Signal, Update, UpdateValue, With, WithUntracked};
+
+use crate::path::split_index;
+use crate::{FieldError, FieldErrors, Form, FormStruct, FormValidator, ValidatorAdapter};
+
+pub(crate) const MIN_ITEMS: &str = "min_items";
+pub(crate) const MAX_ITEMS: &str = "max_items";
+
+/// Type-erased list of rows held by a form field.
+///
+/// Implemented for every `Vec`, so list fields can be exposed through
+/// [`FormStruct::list`] and edited using a [`FieldArray`].
+pub trait FieldList: Any {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the row at `idx`, returning `false` when it is out of range
+    fn remove(&mut self, idx: usize) -> bool;
+
+    /// Swaps the rows at `a` and `b`, returning `false` when either is out
+    /// of range
+    fn swap(&mut self, a: usize, b: usize) -> bool;
+
+    /// Moves the row at `from` to `to`, returning `false` when either is out
+    /// of range
+    fn move_item(&mut self, from: usize, to: usize) -> bool;
+
+    /// Replaces the rows with copies of the rows of `other`, returning
+    /// `false` when `other` holds rows of another type
+    fn assign(&mut self, other: &dyn FieldList) -> bool;
+
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<R: Clone + 'static> FieldList for Vec<R> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn remove(&mut self, idx: usize) -> bool {
+        if idx >= Vec::len(self) {
+            return false;
+        }
+
+        Vec::remove(self, idx);
+        true
+    }
+
+    fn swap(&mut self, a: usize, b: usize) -> bool {
+        if a >= Vec::len(self) || b >= Vec::len(self) {
+            return false;
+        }
+
+        <[R]>::swap(self, a, b);
+        true
+    }
+
+    fn move_item(&mut self, from: usize, to: usize) -> bool {
+        if from >= Vec::len(self) || to >= Vec::len(self) {
+            return false;
+        }
+
+        let row = Vec::remove(self, from);
+        Vec::insert(self, to, row);
+        true
+    }
+
+    fn assign(&mut self, other: &dyn FieldList) -> bool {
+        match other.as_any().downcast_ref::<Vec<R>>() {
+            Some(rows) => {
+                self.clone_from(rows);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Bounds on the number of rows of a list field, such as "at least one
+/// contact".
+///
+/// Violations are reported as errors of the list field itself, with codes
+/// `min_items` and `max_items`, and are checked on submit as well as every
+/// time rows are added or removed through a [`FieldArray`].
+#[derive(Clone, Debug, Default)]
+pub struct ItemCount {
+    min: Option<usize>,
+    max: Option<usize>,
+    min_message: Option<Cow<'static, str>>,
+    max_message: Option<Cow<'static, str>>,
+}
+
+impl ItemCount {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Custom message used when the list has fewer rows than `min`
+    pub fn min_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.min_message = Some(message.into());
+        self
+    }
+
+    /// Custom message used when the list has more rows than `max`
+    pub fn max_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.max_message = Some(message.into());
+        self
+    }
+
+    pub(crate) fn check(&self, count: usize) -> FieldErrors {
+        let mut f_errors = FieldErrors::new();
+
+        if let Some(min) = self.min
+            && count < min
+        {
+            let mut err = FieldError::new(MIN_ITEMS)
+                .with_param("min", min)
+                .with_param("count", count);
+            err.message = self.min_message.clone();
+            f_errors.push(err);
+        }
+
+        if let Some(max) = self.max
+            && count > max
+        {
+            let mut err = FieldError::new(MAX_ITEMS)
+                .with_param("max", max)
+                .with_param("count", count);
+            err.message = self.max_message.clone();
+            f_errors.push(err);
+        }
+
+        f_errors
+    }
+}
+
+/// Row of a [`FieldArray`], identified by a key that stays the same while
+/// the row moves around the list
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldArrayItem {
+    pub key: u64,
+    pub index: usize,
+}
+
+/// Repeatable list of rows held by a form field, such as invoice lines.
+///
+/// Errors, parse errors, touched and edited state and selected files of row
+/// fields, such as `items[2].qty`, follow their row when rows are inserted,
+/// removed or moved.
+pub struct FieldArray<T, R, V = ValidatorAdapter>
+where
+    T: Clone + Default + FormStruct + Send + Sync + 'static,
+    R: Send + Sync + 'static,
+    V: FormValidator<T>,
+{
+    form: Form<T, V>,
+    name: String,
+    row: PhantomData<fn() -> R>,
+}
+
+impl<T, R, V> Clone for FieldArray<T, R, V>
+where
+    T: Clone + Default + FormStruct + Send + Sync + 'static,
+    R: Send + Sync + 'static,
+    V: FormValidator<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            form: self.form,
+            name: self.name.clone(),
+            row: PhantomData,
+        }
+    }
+}
+
+impl<T, R, V> FieldArray<T, R, V>
+where
+    T: Clone + Default + FormStruct + Send + Sync + 'static,
+    R: Send + Sync + 'static,
+    V: FormValidator<T>,
+{
+    pub(crate) fn new(form: Form<T, V>, name: &str) -> Self {
+        let array = Self {
+            form,
+            name: name.to_string(),
+            row: PhantomData,
+        };
+
+        let len = array.len_untracked();
+        array.update_keys(|keys, next_key| {
+            while keys.len() < len {
+                keys.push(next_key());
+            }
+
+            keys.truncate(len);
+        });
+
+        array
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Builds the path of a field of a row, e.g. `items[2].qty`
+    pub fn path(&self, index: usize, field: &str) -> String {
+        format!("{}[{index}].{field}", self.name)
+    }
+
+    pub fn len(&self) -> Signal<usize> {
+        let form = self.form;
+        let name = self.name.clone();
+
+        Memo::new(move |_| form.values.with(|values| list_len(values, &name))).into()
+    }
+
+    pub fn is_empty(&self) -> Signal<bool> {
+        let len = self.len();
+
+        Signal::derive(move || len.with(|len| *len == 0))
+    }
+
+    /// Retrieves the rows of the list along with their stable keys, for use
+    /// with `<For>`
+    pub fn fields(&self) -> Signal<Vec<FieldArrayItem>> {
+        let form = self.form;
+        let name = self.name.clone();
+
+        Memo::new(move |_| {
+            let len = form.values.with(|values| list_len(values, &name));
+
+            form.array_keys.with(|array_keys| {
+                let keys = array_keys.get(&name).map(Vec::as_slice).unwrap_or_default();
+
+                (0..len)
+                    .map(|index| FieldArrayItem {
+                        key: keys
+                            .get(index)
+                            .copied()
+                            .unwrap_or(FALLBACK_KEY | index as u64),
+                        index,
+                    })
+                    .collect()
+            })
+        })
+        .into()
+    }
+
+    /// Adds a row at the end of the list
+    pub fn append(&self, row: R) {
+        if self.with_rows(|rows| rows.push(row)).is_some() {
+            self.update_keys(|keys, next_key| keys.push(next_key()));
+            self.form.validate_item_count(&self.name);
+        }
+    }
+
+    /// Adds a row at the provided index, shifting the following rows
+    pub fn insert(&self, index: usize, row: R) {
+        let inserted = self.with_rows(|rows| {
+            let index = index.min(rows.len());
+            rows.insert(index, row);
+            index
+        });
+
+        if let Some(index) = inserted {
+            self.update_keys(|keys, next_key| keys.insert(index.min(keys.len()), next_key()));
+            self.reindex(|idx| Some(if idx >= index { idx + 1 } else { idx }));
+            self.form.validate_item_count(&self.name);
+        }
+    }
+
+    /// Removes the row at the provided index, along with its errors
+    pub fn remove(&self, index: usize) {
+        if self.with_list(|list| list.remove(index)) {
+            self.update_keys(|keys, _| {
+                if index < keys.len() {
+                    keys.remove(index);
+                }
+            });
+            self.reindex(|idx| match idx.cmp(&index) {
+                std::cmp::Ordering::Less => Some(idx),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(idx - 1),
+            });
+            self.form.validate_item_count(&self.name);
+        }
+    }
+
+    /// Moves the row at `from` to `to`, shifting the rows in between
+    pub fn move_item(&self, from: usize, to: usize) {
+        if self.with_list(|list| list.move_item(from, to)) {
+            self.update_keys(|keys, _| {
+                if from < keys.len() && to < keys.len() {
+                    let key = keys.remove(from);
+                    keys.insert(to, key);
+                }
+            });
+            self.reindex(|idx| {
+                Some(if idx == from {
+                    to
+                } else if from < to && idx > from && idx <= to {
+                    idx - 1
+                } else if to < from && idx >= to && idx < from {
+                    idx + 1
+                } else {
+                    idx
+                })
+            });
+        }
+    }
+
+    /// Swaps the rows at the provided indexes
+    pub fn swap(&self, a: usize, b: usize) {
+        if self.with_list(|list| list.swap(a, b)) {
+            self.update_keys(|keys, _| {
+                if a < keys.len() && b < keys.len() {
+                    keys.swap(a, b);
+                }
+            });
+            self.reindex(|idx| {
+                Some(if idx == a {
+                    b
+                } else if idx == b {
+                    a
+                } else {
+                    idx
+                })
+            });
+        }
+    }
+
+    /// Replaces the row at the provided index, discarding the errors of the
+    /// previous row
+    pub fn replace(&self, index: usize, row: R) {
+        let replaced = self.with_rows(|rows| match rows.get_mut(index) {
+            Some(current) => {
+                *current = row;
+                true
+            }
+            None => false,
+        });
+
+        if replaced == Some(true) {
+            self.reindex(|idx| (idx != index).then_some(idx));
+        }
+    }
+
+    fn len_untracked(&self) -> usize {
+        self.form
+            .values
+            .with_untracked(|values| list_len(values, &self.name))
+    }
+
+    /// Edits the list, returning whether `f` changed it
+    fn with_list(&self, f: impl FnOnce(&mut dyn FieldList) -> bool) -> bool {
+        self.update_list(|list| f(list).then_some(())).is_some()
+    }
+
+    fn with_rows<U>(&self, f: impl FnOnce(&mut Vec<R>) -> U) -> Option<U> {
+        self.update_list(|list| match list.as_any_mut().downcast_mut::<Vec<R>>() {
+            Some(rows) => Some(f(rows)),
+            None => {
+                leptos::logging::error!(
+                    "field `{}` does not hold rows of type `{}`",
+                    self.name,
+                    std::any::type_name::<R>()
+                );
+                None
+            }
+        })
+    }
+
+    /// Edits the list, notifying the form values and marking the field as
+    /// edited only when `f` returns `Some`
+    fn update_list<U>(&self, f: impl FnOnce(&mut dyn FieldList) -> Option<U>) -> Option<U> {
+        let out = self
+            .form
+            .values
+            .try_maybe_update(|values| {
+                let out = values.list_mut(&self.name).and_then(f);
+                (out.is_some(), out)
+            })
+            .flatten();
+
+        if out.is_some() {
+            self.form.mark_edited(&self.name);
+        }
+
+        out
+    }
+
+    fn update_keys(&self, f: impl FnOnce(&mut Vec<u64>, &mut dyn FnMut() -> u64)) {
+        let next_key = self.form.next_key;
+
+        self.form.array_keys.update(|array_keys| {
+            let keys = array_keys.entry(self.name.clone()).or_default();
+
+            next_key.update_value(|next| {
+                let mut next_key = || {
+                    *next += 1;
+                    *next
+                };
+
+                f(keys, &mut next_key);
+            });
+        });
+    }
+
+    /// Moves the state of row fields to the row index returned by `f`,
+    /// dropping it when `f` returns `None`.
+    ///
+    /// Checks of [`AsyncValidator`](crate::AsyncValidator)s made for row
+    /// fields are dropped, so their results cannot land on another row.
+    fn reindex(&self, f: impl Fn(usize) -> Option<usize>) {
+        self.form.cancel_async_validation(|path| {
+            path.strip_prefix(self.name.as_str())
+                .and_then(split_index)
+                .is_some()
+        });
+        self.form
+            .errors
+            .update(|errors| reindex_map(errors, &self.name, &f));
+        self.form
+            .parse_errors
+            .update(|errors| reindex_map(errors, &self.name, &f));
+        self.form
+            .touched
+            .update(|touched| reindex_set(touched, &self.name, &f));
+        self.form
+            .edited
+            .update(|edited| reindex_set(edited, &self.name, &f));
+        self.form
+            .files
+            .update(|files| reindex_map(files, &self.name, &f));
+    }
+}
+
+/// Marks keys of rows added without going through a [`FieldArray`]
+const FALLBACK_KEY: u64 = 1 << 63;
+
+pub(crate) fn list_len<T: FormStruct>(values: &T, name: &str) -> usize {
+    values.list(name).map(FieldList::len).unwrap_or_default()
+}
+
+/// Rewrites a path under the list `name` to the row index returned by `f`.
+///
+/// Returns `None` for paths outside the list, which are left as is.
+fn reindex_path(
+    path: &str,
+    name: &str,
+    f: &impl Fn(usize) -> Option<usize>,
+) -> Option<Option<String>> {
+    let (idx, rest) = split_index(path.strip_prefix(name)?)?;
+
+    Some(f(idx).map(|idx| format!("{name}[{idx}]{rest}")))
+}
+
+fn reindex_map<V>(map: &mut HashMap<String, V>, name: &str, f: &impl Fn(usize) -> Option<usize>) {
+    *map = std::mem::take(map)
+        .into_iter()
+        .filter_map(|(path, value)| match reindex_path(&path, name, f) {
+            Some(path) => path.map(|path| (path, value)),
+            None => Some((path, value)),
+        })
+        .collect();
+}
+
+fn reindex_set(set: &mut HashSet<String>, name: &str, f: &impl Fn(usize) -> Option<usize>) {
+    *set = std::mem::take(set)
+        .into_iter()
+        .filter_map(|path| match reindex_path(&path, name, f) {
+            Some(path) => path,
+            None => Some(path),
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(f_errors: &FieldErrors) -> Vec<&str> {
+        f_errors.iter().map(|err| err.code.as_ref()).collect()
+    }
+
+    #[test]
+    fn item_count_checks_bounds_inclusively() {
+        let count = ItemCount::new().min(1).max(3);
+
+        assert_eq!(codes(&count.check(0)), [MIN_ITEMS]);
+        assert!(count.check(1).is_empty());
+        assert!(count.check(3).is_empty());
+        assert_eq!(codes(&count.check(4)), [MAX_ITEMS]);
+        assert!(ItemCount::new().check(100).is_empty());
+    }
+
+    #[test]
+    fn item_count_errors_carry_params_and_messages() {
+        let count = ItemCount::new()
+            .min(2)
+            .max(2)
+            .min_message("add another contact")
+            .max_message("too many contacts");
+
+        let f_errors = count.check(1);
+        let err = f_errors.first().unwrap();
+        assert_eq!(err.message.as_deref(), Some("add another contact"));
+        assert_eq!(err.params["min"], 2);
+        assert_eq!(err.params["count"], 1);
+
+        let f_errors = count.check(3);
+        let err = f_errors.first().unwrap();
+        assert_eq!(err.message.as_deref(), Some("too many contacts"));
+        assert_eq!(err.params["max"], 2);
+
+        assert_eq!(
+            ItemCount::new().min(1).check(0).first().unwrap().message,
+            None
+        );
+    }
+
+    /// Index mapping of removing the row at index 1
+    fn remove_second(idx: usize) -> Option<usize> {
+        match idx {
+            0 => Some(0),
+            1 => None,
+            idx => Some(idx - 1),
+        }
+    }
+
+    #[test]
+    fn reindex_path_moves_rows_of_the_list() {
+        let f = &remove_second;
+
+        assert_eq!(
+            reindex_path("items[0].qty", "items", f),
+            Some(Some("items[0].qty".to_string()))
+        );
+        assert_eq!(reindex_path("items[1].qty", "items", f), Some(None));
+        assert_eq!(
+            reindex_path("items[3]", "items", f),
+            Some(Some("items[2]".to_string()))
+        );
+        assert_eq!(
+            reindex_path("items[2].tags[4]", "items", f),
+            Some(Some("items[1].tags[4]".to_string()))
+        );
+    }
+
+    #[test]
+    fn reindex_path_leaves_other_paths() {
+        let f = &remove_second;
+
+        assert_eq!(reindex_path("items", "items", f), None);
+        assert_eq!(reindex_path("items_old[2]", "items", f), None);
+        assert_eq!(reindex_path("name", "items", f), None);
+        assert_eq!(reindex_path("order.items[2]", "items", f), None);
+    }
+
+    #[test]
+    fn reindex_map_drops_removed_rows() {
+        let mut map = HashMap::from([
+            ("items[0].qty".to_string(), 0),
+            ("items[1].qty".to_string(), 1),
+            ("items[2].qty".to_string(), 2),
+            ("name".to_string(), 3),
+        ]);
+
+        reindex_map(&mut map, "items", &remove_second);
+
+        assert_eq!(
+            map,
+            HashMap::from([
+                ("items[0].qty".to_string(), 0),
+                ("items[1].qty".to_string(), 2),
+                ("name".to_string(), 3),
+            ])
+        );
+    }
+
+    #[test]
+    fn reindex_set_drops_removed_rows() {
+        let mut set = HashSet::from(["items[1]".to_string(), "items[2].sku".to_string()]);
+
+        reindex_set(&mut set, "items", &remove_second);
+
+        assert_eq!(set, HashSet::from(["items[1].sku".to_string()]));
+    }
+}
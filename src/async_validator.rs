@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use leptos::prelude::TimeoutHandle;
+use web_time::Instant;
+
+use crate::{FieldError, FieldValue};
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+const DEFAULT_CACHE_SIZE: usize = 32;
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+type ValidateFuture = Pin<Box<dyn Future<Output = Result<(), FieldError>>>>;
+
+/// Asynchronous check of a single form field, such as "is this username
+/// available".
+///
+/// Runs once the field passes its synchronous validation and the user stops
+/// typing for the debounce delay. Results of checks made for values the
+/// field no longer holds are ignored.
+///
+/// Submitting the form runs the check right away for values not checked yet,
+/// and the submission completes once every pending check settles. Errors
+/// returned by the check are merged into the errors of the field.
+///
+/// Results are cached by value for a while, so going back to a value that
+/// was already checked does not run the check again. Clones of the validator
+/// share the same cache.
+#[derive(Clone)]
+pub struct AsyncValidator {
+    validate: Arc<dyn Fn(FieldValue) -> ValidateFuture + Send + Sync>,
+    debounce: Duration,
+    cache: Arc<Mutex<VecDeque<CacheEntry>>>,
+    cache_size: usize,
+    cache_ttl: Duration,
+}
+
+impl AsyncValidator {
+    pub fn new<F, Fut>(validate: F) -> Self
+    where
+        F: Fn(FieldValue) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), FieldError>> + 'static,
+    {
+        Self {
+            validate: Arc::new(move |value| Box::pin(validate(value))),
+            debounce: DEFAULT_DEBOUNCE,
+            cache: Arc::new(Mutex::new(VecDeque::new())),
+            cache_size: DEFAULT_CACHE_SIZE,
+            cache_ttl: DEFAULT_CACHE_TTL,
+        }
+    }
+
+    /// Time to wait after the last change before running the check,
+    /// defaults to 300ms.
+    ///
+    /// Outside the browser, such as during server-side rendering, there are
+    /// no timers to wait on, so checks run right away.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        self.debounce = delay;
+        self
+    }
+
+    /// Maximum number of values whose result is cached, defaults to 32.
+    ///
+    /// Once full, the oldest result is dropped. A size of zero disables the
+    /// cache.
+    pub fn cache_size(mut self, size: usize) -> Self {
+        self.cache_size = size;
+        self
+    }
+
+    /// Time a cached result stays valid, defaults to 5 minutes
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Drops every cached result, e.g. once the username the check was made
+    /// for has been taken
+    pub fn invalidate(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.clear();
+        }
+    }
+
+    pub(crate) fn delay(&self) -> Duration {
+        self.debounce
+    }
+
+    pub(crate) fn validate(&self, value: FieldValue) -> ValidateFuture {
+        (self.validate)(value)
+    }
+
+    /// Retrieves the cached result of the check for a value, if it has not
+    /// expired
+    pub(crate) fn cached(&self, value: &FieldValue) -> Option<Option<FieldError>> {
+        if self.cache_size == 0 {
+            return None;
+        }
+
+        let now = Instant::now();
+        let mut cache = self.cache.lock().ok()?;
+
+        cache.retain(|entry| entry.expires_at > now);
+        cache
+            .iter()
+            .find(|entry| entry.value == *value)
+            .map(|entry| entry.error.clone())
+    }
+
+    pub(crate) fn store(&self, value: FieldValue, error: Option<FieldError>) {
+        if self.cache_size == 0 {
+            return;
+        }
+
+        let Ok(mut cache) = self.cache.lock() else {
+            return;
+        };
+
+        cache.retain(|entry| entry.value != value);
+        cache.push_back(CacheEntry {
+            value,
+            error,
+            expires_at: Instant::now() + self.cache_ttl,
+        });
+
+        while cache.len() > self.cache_size {
+            cache.pop_front();
+        }
+    }
+}
+
+impl Debug for AsyncValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncValidator")
+            .field("debounce", &self.debounce)
+            .field("cache_size", &self.cache_size)
+            .field("cache_ttl", &self.cache_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result of the check for a value, cached by an [`AsyncValidator`]
+#[derive(Clone, Debug)]
+struct CacheEntry {
+    value: FieldValue,
+    error: Option<FieldError>,
+    /// Instant after which the result is dropped
+    expires_at: Instant,
+}
+
+/// Latest run of the [`AsyncValidator`] of a field
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AsyncRun {
+    /// Incremented on every run, so results of previous runs are dropped
+    pub(crate) generation: u64,
+    /// Value checked by the run
+    pub(crate) value: Option<FieldValue>,
+    /// Debounce timeout of the run, while it has not started
+    pub(crate) timeout: Option<TimeoutHandle>,
+}
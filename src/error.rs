@@ -0,0 +1,353 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(feature = "validator")]
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::FieldParseError;
+
+/// Single error of a form field, such as a violated validation rule
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Identifies the violated rule, such as `length` or `email`
+    pub code: Cow<'static, str>,
+    /// Custom message provided for the error, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Cow<'static, str>>,
+    /// Parameters of the violated rule, such as `min` and `max`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<Cow<'static, str>, Value>,
+}
+
+impl FieldError {
+    pub fn new(code: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: None,
+            params: HashMap::new(),
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_param(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+}
+
+#[cfg(feature = "validator")]
+impl From<&ValidationError> for FieldError {
+    fn from(err: &ValidationError) -> Self {
+        Self {
+            code: err.code.clone(),
+            message: err.message.clone(),
+            params: err.params.clone(),
+        }
+    }
+}
+
+impl From<FieldParseError> for FieldError {
+    fn from(err: FieldParseError) -> Self {
+        FieldError::new(err.code().to_string()).with_message(err.message().to_string())
+    }
+}
+
+/// Every error of a single form field, in the order they were reported
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldErrors(Vec<FieldError>);
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, error: FieldError) {
+        self.0.push(error);
+    }
+
+    /// Keeps only the errors for which `f` returns `true`
+    pub fn retain(&mut self, f: impl FnMut(&FieldError) -> bool) {
+        self.0.retain(f);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn first(&self) -> Option<&FieldError> {
+        self.0.first()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.0.iter()
+    }
+
+    pub fn as_slice(&self) -> &[FieldError] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<FieldError> {
+        self.0
+    }
+}
+
+impl From<Vec<FieldError>> for FieldErrors {
+    fn from(errors: Vec<FieldError>) -> Self {
+        Self(errors)
+    }
+}
+
+impl Extend<FieldError> for FieldErrors {
+    fn extend<I: IntoIterator<Item = FieldError>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<FieldError> for FieldErrors {
+    fn from_iter<I: IntoIterator<Item = FieldError>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for FieldErrors {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FieldErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Errors of a whole form: the errors of each field, by field path, along
+/// with form-level errors not tied to any field.
+///
+/// Serialisable, so server functions can return it when rejecting a
+/// submission and the client can apply it using
+/// [`Form::set_server_errors`](crate::Form::set_server_errors).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormErrors {
+    /// Errors of each field, keyed by field path such as `items[2].qty`
+    #[serde(default)]
+    pub fields: HashMap<String, FieldErrors>,
+    /// Errors of the form as a whole, such as "invalid credentials"
+    #[serde(default)]
+    pub form: FieldErrors,
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error to a field
+    pub fn with_field_error(mut self, field: impl Into<String>, error: FieldError) -> Self {
+        self.push_field_error(field, error);
+        self
+    }
+
+    /// Adds an error to the form as a whole
+    pub fn with_form_error(mut self, error: FieldError) -> Self {
+        self.push_form_error(error);
+        self
+    }
+
+    pub fn push_field_error(&mut self, field: impl Into<String>, error: FieldError) {
+        self.fields.entry(field.into()).or_default().push(error);
+    }
+
+    pub fn push_form_error(&mut self, error: FieldError) {
+        self.form.push(error);
+    }
+
+    /// Retrieves the errors of a field, if any
+    pub fn field(&self, field: &str) -> Option<&FieldErrors> {
+        self.fields.get(field)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.form.is_empty() && self.fields.values().all(FieldErrors::is_empty)
+    }
+}
+
+/// Collects the errors reported by [`validator`].
+///
+/// Errors of nested structs and lists are flattened into dotted and indexed
+/// field paths, such as `address.street` and `items[2].qty`, while
+/// struct-level errors reported under `__all__` become form-level errors, or
+/// errors of the field holding the nested struct.
+#[cfg(feature = "validator")]
+impl From<&ValidationErrors> for FormErrors {
+    fn from(err: &ValidationErrors) -> Self {
+        let mut out = FormErrors::new();
+
+        flatten_errors(err, None, &mut out);
+        out
+    }
+}
+
+impl From<HashMap<String, FieldErrors>> for FormErrors {
+    fn from(fields: HashMap<String, FieldErrors>) -> Self {
+        Self {
+            fields,
+            form: FieldErrors::new(),
+        }
+    }
+}
+
+/// Name under which [`validator`] reports struct-level errors
+#[cfg(feature = "validator")]
+const STRUCT_ERRORS: &str = "__all__";
+
+#[cfg(feature = "validator")]
+fn flatten_errors(err: &ValidationErrors, prefix: Option<&str>, out: &mut FormErrors) {
+    for (field, kind) in err.errors() {
+        let path = match prefix {
+            Some(prefix) if *field == STRUCT_ERRORS => prefix.to_string(),
+            Some(prefix) => format!("{prefix}.{field}"),
+            None => field.to_string(),
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(f_errors) => {
+                let entry: &mut FieldErrors = if path == STRUCT_ERRORS {
+                    &mut out.form
+                } else {
+                    out.fields.entry(path).or_default()
+                };
+
+                for err in f_errors {
+                    entry.push(FieldError::from(err));
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => {
+                flatten_errors(nested, Some(&path), out);
+            }
+            ValidationErrorsKind::List(items) => {
+                for (idx, nested) in items {
+                    flatten_errors(nested, Some(&format!("{path}[{idx}]")), out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_errors_round_trip_through_json() {
+        let errors = FormErrors::new()
+            .with_field_error(
+                "items[2].qty",
+                FieldError::new("range")
+                    .with_message("not enough stock")
+                    .with_param("max", 4),
+            )
+            .with_field_error("email", FieldError::new("taken"))
+            .with_form_error(FieldError::new("credentials"));
+
+        let json = serde_json::to_value(&errors).unwrap();
+
+        assert_eq!(
+            json["fields"]["items[2].qty"],
+            serde_json::json!([{
+                "code": "range",
+                "message": "not enough stock",
+                "params": { "max": 4 },
+            }])
+        );
+        assert_eq!(json["form"], serde_json::json!([{ "code": "credentials" }]));
+        assert_eq!(serde_json::from_value::<FormErrors>(json).unwrap(), errors);
+    }
+
+    #[test]
+    fn form_errors_fields_default_when_missing() {
+        let errors: FormErrors = serde_json::from_str(r#"{"form":[{"code":"busy"}]}"#).unwrap();
+
+        assert!(errors.fields.is_empty());
+        assert_eq!(errors.form.first(), Some(&FieldError::new("busy")));
+    }
+
+    #[cfg(feature = "validator")]
+    mod flatten {
+        use std::collections::BTreeMap;
+
+        use super::*;
+
+        fn errors(fields: &[(&'static str, &'static str)]) -> ValidationErrors {
+            let mut errors = ValidationErrors::new();
+
+            for (field, code) in fields {
+                errors.add(field, ValidationError::new(code));
+            }
+
+            errors
+        }
+
+        fn codes(errors: Option<&FieldErrors>) -> Vec<&str> {
+            errors
+                .map(|errors| errors.iter().map(|err| err.code.as_ref()).collect())
+                .unwrap_or_default()
+        }
+
+        #[test]
+        fn struct_errors_of_the_form_are_form_level() {
+            let out = FormErrors::from(&errors(&[("name", "length"), ("__all__", "passwords")]));
+
+            assert_eq!(codes(out.field("name")), ["length"]);
+            assert_eq!(codes(Some(&out.form)), ["passwords"]);
+            assert!(out.field("__all__").is_none());
+        }
+
+        #[test]
+        fn nested_errors_are_keyed_by_path() {
+            let mut items = BTreeMap::new();
+            items.insert(2, Box::new(errors(&[("qty", "range")])));
+
+            let mut err = errors(&[("email", "email")]);
+            err.errors_mut().insert(
+                "shipping".into(),
+                ValidationErrorsKind::Struct(Box::new(errors(&[
+                    ("city", "required"),
+                    ("__all__", "address"),
+                ]))),
+            );
+            err.errors_mut()
+                .insert("items".into(), ValidationErrorsKind::List(items));
+
+            let out = FormErrors::from(&err);
+
+            assert_eq!(codes(out.field("email")), ["email"]);
+            assert_eq!(codes(out.field("shipping.city")), ["required"]);
+            assert_eq!(codes(out.field("shipping")), ["address"]);
+            assert_eq!(codes(out.field("items[2].qty")), ["range"]);
+            assert!(out.form.is_empty());
+            assert_eq!(out.fields.len(), 4);
+        }
+    }
+}
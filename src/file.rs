@@ -0,0 +1,117 @@
+use web_sys::File;
+
+use crate::{FieldError, FieldErrors};
+
+/// Client-side rules for the files selected in a file input.
+///
+/// Violations are reported as errors of the file field, with codes
+/// `file_size`, `file_type`, `min_files` and `max_files`.
+#[derive(Clone, Debug, Default)]
+pub struct FileRules {
+    max_size: Option<u64>,
+    accept: Vec<String>,
+    min_files: Option<usize>,
+    max_files: Option<usize>,
+}
+
+impl FileRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum size of every file, in bytes
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Allowed file types, following the format of the HTML `accept`
+    /// attribute: MIME types such as `image/png`, MIME wildcards such as
+    /// `image/*` and extensions such as `.pdf`
+    pub fn accept<I, S>(mut self, accept: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.accept = accept.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn min_files(mut self, min: usize) -> Self {
+        self.min_files = Some(min);
+        self
+    }
+
+    pub fn max_files(mut self, max: usize) -> Self {
+        self.max_files = Some(max);
+        self
+    }
+
+    pub(crate) fn check(&self, files: &[File]) -> FieldErrors {
+        let mut f_errors = FieldErrors::new();
+
+        if let Some(min) = self.min_files
+            && files.len() < min
+        {
+            f_errors.push(
+                FieldError::new("min_files")
+                    .with_param("min", min)
+                    .with_param("count", files.len()),
+            );
+        }
+
+        if let Some(max) = self.max_files
+            && files.len() > max
+        {
+            f_errors.push(
+                FieldError::new("max_files")
+                    .with_param("max", max)
+                    .with_param("count", files.len()),
+            );
+        }
+
+        for file in files {
+            if let Some(max) = self.max_size
+                && file.size() > max as f64
+            {
+                f_errors.push(
+                    FieldError::new("file_size")
+                        .with_param("name", file.name())
+                        .with_param("max", max)
+                        .with_param("size", file.size()),
+                );
+            }
+
+            if !self.accept.is_empty() && !self.accepts(file) {
+                f_errors.push(
+                    FieldError::new("file_type")
+                        .with_param("name", file.name())
+                        .with_param("accept", self.accept.join(", "))
+                        .with_param("type", file.type_()),
+                );
+            }
+        }
+
+        f_errors
+    }
+
+    fn accepts(&self, file: &File) -> bool {
+        let name = file.name().to_lowercase();
+        let mime = file.type_().to_lowercase();
+
+        self.accept.iter().any(|accept| {
+            let accept = accept.trim().to_lowercase();
+
+            if accept.starts_with('.') {
+                return name.ends_with(&accept);
+            }
+
+            match accept.strip_suffix("/*") {
+                Some(group) => mime
+                    .split_once('/')
+                    .is_some_and(|(mime_group, _)| mime_group == group),
+                None => mime == accept,
+            }
+        })
+    }
+}
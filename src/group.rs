@@ -0,0 +1,138 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::path::is_within;
+use crate::{FormErrors, FormValidator};
+
+/// Subset of the validation of a form, such as the checks needed to save a
+/// draft as opposed to publishing it.
+///
+/// Groups are registered using
+/// [`Form::with_validation_group`](crate::Form::with_validation_group) and
+/// picked by submit handlers such as
+/// [`Form::handle_submit_group`](crate::Form::handle_submit_group). While a
+/// group is in effect, errors outside of it are not reported.
+pub struct ValidationGroup<T> {
+    fields: Option<Vec<String>>,
+    validator: Option<Arc<dyn FormValidator<T>>>,
+}
+
+impl<T> ValidationGroup<T> {
+    /// Creates a group running every check of the form
+    pub fn new() -> Self {
+        Self {
+            fields: None,
+            validator: None,
+        }
+    }
+
+    /// Reports only the errors of the provided fields, and of the fields
+    /// nested in them, leaving out form-level errors
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Checks the values using the provided [`FormValidator`] instead of the
+    /// one of the form
+    pub fn validator(mut self, validator: impl FormValidator<T>) -> Self {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    pub(crate) fn form_validator(&self) -> Option<&dyn FormValidator<T>> {
+        self.validator.as_deref()
+    }
+
+    /// Whether errors of the field with the provided path are reported
+    pub(crate) fn covers(&self, path: &str) -> bool {
+        let Some(fields) = &self.fields else {
+            return true;
+        };
+
+        fields.iter().any(|field| is_within(path, field))
+    }
+
+    /// Drops the errors not reported by the group
+    pub(crate) fn retain(&self, errors: &mut FormErrors) {
+        if self.fields.is_none() {
+            return;
+        }
+
+        errors.fields.retain(|path, _| self.covers(path));
+        errors.form = Default::default();
+    }
+}
+
+impl<T> Clone for ValidationGroup<T> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+}
+
+impl<T> Default for ValidationGroup<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for ValidationGroup<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidationGroup")
+            .field("fields", &self.fields)
+            .field("validator", &self.validator.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldError;
+
+    #[test]
+    fn group_without_fields_covers_everything() {
+        let group = ValidationGroup::<()>::new();
+
+        assert!(group.covers("name"));
+        assert!(group.covers("items[0].qty"));
+    }
+
+    #[test]
+    fn group_covers_listed_fields_and_their_children() {
+        let group = ValidationGroup::<()>::new().fields(["title", "items"]);
+
+        assert!(group.covers("title"));
+        assert!(group.covers("items"));
+        assert!(group.covers("items[2].qty"));
+        assert!(!group.covers("title_draft"));
+        assert!(!group.covers("body"));
+
+        let group = ValidationGroup::<()>::new().fields(["shipping"]);
+
+        assert!(group.covers("shipping.city"));
+        assert!(!group.covers("shippingcity"));
+    }
+
+    #[test]
+    fn retain_drops_uncovered_and_form_errors() {
+        let group = ValidationGroup::<()>::new().fields(["title"]);
+        let mut errors = FormErrors::new()
+            .with_field_error("title", FieldError::new("required"))
+            .with_field_error("body", FieldError::new("required"))
+            .with_form_error(FieldError::new("conflict"));
+
+        group.retain(&mut errors);
+
+        assert!(errors.field("title").is_some());
+        assert!(errors.field("body").is_none());
+        assert!(errors.form.is_empty());
+    }
+}
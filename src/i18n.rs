@@ -0,0 +1,36 @@
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "leptos_i18n")]
+mod leptos_i18n;
+
+use crate::FieldError;
+
+#[cfg(feature = "leptos_i18n")]
+pub use self::leptos_i18n::LeptosI18nTranslator;
+#[cfg(feature = "fluent")]
+pub use fluent::FluentTranslator;
+
+/// Translates form errors into display text for a locale.
+///
+/// Returning `None` falls back to the [`MessageResolver`] of the form, so
+/// translators only need to know about the messages they translate.
+///
+/// Adapters are provided for Fluent and `leptos_i18n`, behind the `fluent`
+/// and `leptos_i18n` features. Closures of the form
+/// `Fn(&str, &FieldError) -> Option<String>` implement this trait as well,
+/// mapping the error `code` (or its custom `message`, when used as a
+/// translation key) to a translated string.
+///
+/// [`MessageResolver`]: crate::MessageResolver
+pub trait MessageTranslator: Send + Sync + 'static {
+    fn translate(&self, locale: &str, err: &FieldError) -> Option<String>;
+}
+
+impl<F> MessageTranslator for F
+where
+    F: Fn(&str, &FieldError) -> Option<String> + Send + Sync + 'static,
+{
+    fn translate(&self, locale: &str, err: &FieldError) -> Option<String> {
+        self(locale, err)
+    }
+}
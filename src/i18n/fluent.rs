@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use serde_json::Value;
+
+use crate::{FieldError, MessageTranslator};
+
+/// [`MessageTranslator`] backed by [Fluent](https://projectfluent.org) bundles.
+///
+/// Errors are looked up by their custom `message`, used as the message id,
+/// falling back to their `code`. Error params are available as Fluent
+/// variables, e.g. `{ $min }`.
+#[derive(Default)]
+pub struct FluentTranslator {
+    bundles: HashMap<String, FluentBundle<FluentResource>>,
+}
+
+impl FluentTranslator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the bundle used for the provided locale
+    pub fn with_bundle(
+        mut self,
+        locale: impl Into<String>,
+        bundle: FluentBundle<FluentResource>,
+    ) -> Self {
+        self.bundles.insert(locale.into(), bundle);
+        self
+    }
+}
+
+impl MessageTranslator for FluentTranslator {
+    fn translate(&self, locale: &str, err: &FieldError) -> Option<String> {
+        let bundle = self.bundles.get(locale)?;
+        let message = err
+            .message
+            .as_deref()
+            .and_then(|id| bundle.get_message(id))
+            .or_else(|| bundle.get_message(&err.code))?;
+        let pattern = message.value()?;
+
+        let mut args = FluentArgs::new();
+
+        for (name, value) in &err.params {
+            let value = match value {
+                Value::String(value) => FluentValue::from(value.as_str()),
+                Value::Number(value) => match value.as_f64() {
+                    Some(value) => FluentValue::from(value),
+                    None => FluentValue::from(value.to_string()),
+                },
+                value => FluentValue::from(value.to_string()),
+            };
+
+            args.set(name.as_ref(), value);
+        }
+
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, Some(&args), &mut errors);
+
+        Some(text.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translator() -> FluentTranslator {
+        let resource = FluentResource::try_new(
+            [
+                "length = doit contenir au moins { $min } caractères",
+                "required = { $field } est obligatoire",
+                "username-taken = ce nom est déjà pris",
+                "range = hors limites { $range }",
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        let mut bundle = FluentBundle::new_concurrent(Vec::new());
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource).unwrap();
+
+        FluentTranslator::new().with_bundle("fr", bundle)
+    }
+
+    #[test]
+    fn messages_are_looked_up_by_message_then_code() {
+        let translator = translator();
+
+        assert_eq!(
+            translator.translate(
+                "fr",
+                &FieldError::new("custom").with_message("username-taken")
+            ),
+            Some("ce nom est déjà pris".to_string())
+        );
+        assert!(
+            translator
+                .translate("fr", &FieldError::new("length").with_message("Too short!"))
+                .is_some_and(|text| text.starts_with("doit contenir au moins"))
+        );
+        assert_eq!(translator.translate("fr", &FieldError::new("email")), None);
+        assert_eq!(translator.translate("de", &FieldError::new("length")), None);
+    }
+
+    #[test]
+    fn params_are_passed_as_variables() {
+        let translator = translator();
+
+        assert_eq!(
+            translator.translate("fr", &FieldError::new("length").with_param("min", 3)),
+            Some("doit contenir au moins 3 caractères".to_string())
+        );
+        assert_eq!(
+            translator.translate(
+                "fr",
+                &FieldError::new("required").with_param("field", "Nom")
+            ),
+            Some("Nom est obligatoire".to_string())
+        );
+        assert_eq!(
+            translator.translate(
+                "fr",
+                &FieldError::new("range").with_param("range", serde_json::json!([1, 2]))
+            ),
+            Some("hors limites [1,2]".to_string())
+        );
+    }
+}
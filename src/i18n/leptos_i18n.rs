@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use leptos::prelude::Signal;
+use leptos_i18n::{I18nContext, Locale};
+
+use crate::{FieldError, MessageTranslator};
+
+type TranslateFn<L> = dyn Fn(L, &FieldError) -> Option<String> + Send + Sync;
+
+/// [`MessageTranslator`] backed by the locales of
+/// [`leptos_i18n`](https://docs.rs/leptos_i18n).
+///
+/// Translation keys of `leptos_i18n` are checked at compile time, so errors
+/// are mapped to translations by a function receiving the locale of the
+/// form, e.g. matching on the error `code` and using `td_string!`. Locales
+/// unknown to `L` fall back to the [`MessageResolver`].
+///
+/// [`MessageResolver`]: crate::MessageResolver
+pub struct LeptosI18nTranslator<L: Locale> {
+    translate: Arc<TranslateFn<L>>,
+}
+
+impl<L: Locale> LeptosI18nTranslator<L> {
+    pub fn new<F>(translate: F) -> Self
+    where
+        F: Fn(L, &FieldError) -> Option<String> + Send + Sync + 'static,
+    {
+        Self {
+            translate: Arc::new(translate),
+        }
+    }
+
+    /// Tracks the locale of a `leptos_i18n` context, to be provided along
+    /// with the translator to
+    /// [`Form::with_translator`](crate::Form::with_translator)
+    pub fn locale(i18n: I18nContext<L>) -> Signal<String> {
+        Signal::derive(move || Locale::as_str(i18n.get_locale()).to_string())
+    }
+}
+
+impl<L: Locale> Clone for LeptosI18nTranslator<L> {
+    fn clone(&self) -> Self {
+        Self {
+            translate: self.translate.clone(),
+        }
+    }
+}
+
+impl<L: Locale> MessageTranslator for LeptosI18nTranslator<L> {
+    fn translate(&self, locale: &str, err: &FieldError) -> Option<String> {
+        let locale = locale.parse::<L>().ok()?;
+
+        (self.translate)(locale, err)
+    }
+}
@@ -0,0 +1,86 @@
+use wasm_bindgen::JsCast;
+use web_sys::{
+    EventTarget, File, HtmlInputElement, HtmlOptionElement, HtmlSelectElement, HtmlTextAreaElement,
+};
+
+use crate::FieldValue;
+
+/// Value read from a form control when its value changes
+pub(crate) enum InputValue {
+    /// Replaces the value of the field
+    Set(FieldValue),
+    /// Adds or removes an entry from a list field, as checkbox groups do
+    Toggle { value: String, checked: bool },
+    /// Replaces the files selected for a file field
+    Files(Vec<File>),
+    /// Leaves the field untouched, as unchecked radio buttons do
+    Ignore,
+}
+
+/// Retrieves the name of the form control targeted by an event
+pub(crate) fn field_name(target: &EventTarget) -> Option<String> {
+    if let Some(el) = target.dyn_ref::<HtmlInputElement>() {
+        return Some(el.name());
+    }
+
+    if let Some(el) = target.dyn_ref::<HtmlSelectElement>() {
+        return Some(el.name());
+    }
+
+    if let Some(el) = target.dyn_ref::<HtmlTextAreaElement>() {
+        return Some(el.name());
+    }
+
+    None
+}
+
+/// Reads the value of the form control targeted by an event.
+///
+/// `is_list` tells whether the field currently holds a list, in which case
+/// checkboxes behave as a group rather than as a single boolean.
+pub(crate) fn read_input(target: &EventTarget, is_list: bool) -> Option<InputValue> {
+    if let Some(el) = target.dyn_ref::<HtmlInputElement>() {
+        let value = match el.type_().as_str() {
+            "checkbox" if is_list => InputValue::Toggle {
+                value: el.value(),
+                checked: el.checked(),
+            },
+            "checkbox" => InputValue::Set(FieldValue::Bool(el.checked())),
+            "radio" if el.checked() => InputValue::Set(FieldValue::Text(el.value())),
+            "radio" => InputValue::Ignore,
+            "file" => {
+                let files = el.files();
+                let files = files
+                    .iter()
+                    .flat_map(|files| (0..files.length()).filter_map(|idx| files.item(idx)))
+                    .collect();
+
+                InputValue::Files(files)
+            }
+            _ => InputValue::Set(FieldValue::Text(el.value())),
+        };
+
+        return Some(value);
+    }
+
+    if let Some(el) = target.dyn_ref::<HtmlSelectElement>() {
+        if !el.multiple() {
+            return Some(InputValue::Set(FieldValue::Text(el.value())));
+        }
+
+        let options = el.selected_options();
+        let selected = (0..options.length())
+            .filter_map(|idx| options.item(idx))
+            .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+            .map(|option| FieldValue::Text(option.value()))
+            .collect();
+
+        return Some(InputValue::Set(FieldValue::List(selected)));
+    }
+
+    if let Some(el) = target.dyn_ref::<HtmlTextAreaElement>() {
+        return Some(InputValue::Set(FieldValue::Text(el.value())));
+    }
+
+    None
+}
@@ -1,37 +1,343 @@
-use std::collections::HashMap;
-use std::fmt::Debug;
+mod array;
+mod async_validator;
+mod error;
+mod file;
+mod group;
+mod i18n;
+mod input;
+mod message;
+mod mode;
+mod path;
+mod reset;
+mod state;
+mod validate;
+mod value;
 
-use leptos::ev::Event;
-use leptos::prelude::{event_target_value, Get, Memo, RwSignal, Signal, Update};
+#[cfg(test)]
+extern crate self as urlap;
 
-use validator::Validate;
-use wasm_bindgen::JsCast;
-use web_sys::{HtmlInputElement, SubmitEvent};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::rc::Rc;
+use std::sync::Arc;
 
-pub trait FormStruct: Clone + Debug + Validate {
+use leptos::ev::{Event, FocusEvent};
+use leptos::prelude::{
+    Get, GetUntracked, GetValue, LocalStorage, Memo, RwSignal, Set, SetValue, Signal, StoredValue,
+    Track, Update, UpdateValue, With, WithUntracked, WithValue, set_timeout_with_handle,
+};
+
+use wasm_bindgen::JsValue;
+use web_sys::{File, FormData, SubmitEvent};
+
+use crate::array::{MAX_ITEMS, MIN_ITEMS, list_len};
+use crate::async_validator::AsyncRun;
+use crate::input::{InputValue, field_name, read_input};
+use crate::path::is_within;
+use crate::reset::restore_field;
+
+pub use urlap_macros::FormStruct;
+
+pub use array::{FieldArray, FieldArrayItem, FieldList, ItemCount};
+pub use async_validator::AsyncValidator;
+pub use error::{FieldError, FieldErrors, FormErrors};
+pub use file::FileRules;
+pub use group::ValidationGroup;
+#[cfg(feature = "fluent")]
+pub use i18n::FluentTranslator;
+#[cfg(feature = "leptos_i18n")]
+pub use i18n::LeptosI18nTranslator;
+pub use i18n::MessageTranslator;
+pub use message::MessageResolver;
+pub use mode::ValidationMode;
+pub use path::NestedFields;
+pub use reset::ResetOptions;
+pub use state::FormState;
+#[cfg(feature = "garde")]
+pub use validate::GardeAdapter;
+#[cfg(feature = "validator")]
+pub use validate::validate;
+pub use validate::{FormValidator, ValidatorAdapter};
+pub use value::{FieldParseError, FieldValue, FromFieldValue, IntoFieldValue};
+
+/// Values of a form, accessed by field name.
+///
+/// Field names may be paths into nested values, such as `shipping.city` or
+/// `items[2].qty`, matching the paths used for validation errors.
+pub trait FormStruct: Clone + Debug {
     fn get(&self, name: &str) -> Option<String>;
     fn set(&mut self, name: &str, value: &str);
+
+    /// Writes the text value of a field, failing when the text cannot be
+    /// parsed into the field type.
+    ///
+    /// Defaults to [`FormStruct::set`], which never fails.
+    fn try_set(&mut self, name: &str, value: &str) -> Result<(), FieldParseError> {
+        self.set(name, value);
+        Ok(())
+    }
+
+    /// Retrieves the typed value of a field.
+    ///
+    /// Defaults to the text returned by [`FormStruct::get`].
+    fn get_value(&self, name: &str) -> Option<FieldValue> {
+        self.get(name).map(FieldValue::Text)
+    }
+
+    /// Writes a typed value into a field, failing when the value cannot be
+    /// converted into the field type.
+    ///
+    /// Defaults to writing the text representation using [`FormStruct::set`].
+    fn set_value(&mut self, name: &str, value: FieldValue) -> Result<(), FieldParseError> {
+        self.set(name, &value.to_string());
+        Ok(())
+    }
+
+    /// Lists the paths of every field, such as `shipping.city`, used to
+    /// serialise the values into [`FormData`].
+    ///
+    /// Defaults to no fields.
+    fn field_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Retrieves a list field, to be edited through a [`FieldArray`].
+    ///
+    /// Defaults to `None`, as in no list fields.
+    fn list(&self, name: &str) -> Option<&dyn FieldList> {
+        let _ = name;
+        None
+    }
+
+    fn list_mut(&mut self, name: &str) -> Option<&mut dyn FieldList> {
+        let _ = name;
+        None
+    }
 }
 
-#[derive(Clone)]
-pub struct Form<T: Clone + Default + FormStruct + Send + Sync + 'static> {
+/// Submission waiting for the [`AsyncValidator`]s of the form to settle
+type QueuedSubmit<T> = Box<dyn FnOnce(T)>;
+
+pub struct Form<
+    T: Clone + Default + FormStruct + Send + Sync + 'static,
+    V: FormValidator<T> = ValidatorAdapter,
+> {
+    validator: StoredValue<V>,
+    groups: StoredValue<HashMap<String, ValidationGroup<T>>>,
+    group: StoredValue<Option<String>>,
     values: RwSignal<T>,
-    errors: RwSignal<HashMap<String, Option<String>>>,
+    errors: RwSignal<HashMap<String, FieldErrors>>,
+    form_errors: RwSignal<FieldErrors>,
+    parse_errors: RwSignal<HashMap<String, FieldError>>,
+    touched: RwSignal<HashSet<String>>,
+    edited: RwSignal<HashSet<String>>,
+    initial: RwSignal<T>,
+    array_keys: RwSignal<HashMap<String, Vec<u64>>>,
+    next_key: StoredValue<u64>,
+    item_counts: StoredValue<HashMap<String, ItemCount>>,
+    files: RwSignal<HashMap<String, Vec<File>>, LocalStorage>,
+    file_rules: StoredValue<HashMap<String, FileRules>>,
+    submit_count: RwSignal<usize>,
+    submitting: RwSignal<bool>,
+    submit_successful: RwSignal<bool>,
+    submit_error: RwSignal<Option<String>>,
+    validating: RwSignal<HashSet<String>>,
+    async_validators: StoredValue<HashMap<String, AsyncValidator>>,
+    async_runs: StoredValue<HashMap<String, AsyncRun>>,
+    async_errors: RwSignal<HashMap<String, FieldError>>,
+    queued_submit: StoredValue<Option<QueuedSubmit<T>>, LocalStorage>,
+    messages: StoredValue<MessageResolver>,
+    translator: StoredValue<Option<Arc<dyn MessageTranslator>>>,
+    locale: Option<Signal<String>>,
+    mode: ValidationMode,
+    revalidate_mode: ValidationMode,
 }
 
-impl<T: Clone + Default + FormStruct + Send + Sync + 'static> Form<T> {
-    pub fn new() -> Form<T> {
-        let values: RwSignal<T> = RwSignal::new(Default::default());
-        let errors = RwSignal::new(HashMap::new());
+impl<T: Clone + Default + FormStruct + Send + Sync + 'static, V: FormValidator<T>> Clone
+    for Form<T, V>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Clone + Default + FormStruct + Send + Sync + 'static, V: FormValidator<T>> Copy
+    for Form<T, V>
+{
+}
 
-        Self { values, errors }
+impl<T: Clone + Default + FormStruct + Send + Sync + 'static, V: FormValidator<T>> Form<T, V> {
+    pub fn new() -> Form<T, V>
+    where
+        V: Default,
+    {
+        Self::with_initial_values(Default::default())
     }
 
-    pub fn with_initial_values(values: T) -> Form<T> {
+    pub fn with_initial_values(values: T) -> Form<T, V>
+    where
+        V: Default,
+    {
+        Self::build(V::default(), values)
+    }
+
+    /// Creates a form checked by the provided [`FormValidator`], such as a
+    /// closure of the form `Fn(&T) -> Result<(), FormErrors>`.
+    ///
+    /// Closures not capturing anything can be stored as function pointers,
+    /// which lets the form type be named, e.g.
+    /// `Form<T, fn(&T) -> Result<(), FormErrors>>`.
+    pub fn with_validator(validator: V) -> Form<T, V> {
+        Self::build(validator, Default::default())
+    }
+
+    /// Creates a form checked by the provided [`FormValidator`], starting
+    /// with the provided initial values
+    pub fn with_validator_and_values(validator: V, values: T) -> Form<T, V> {
+        Self::build(validator, values)
+    }
+
+    fn build(validator: V, values: T) -> Form<T, V> {
+        let validator = StoredValue::new(validator);
+        let groups = StoredValue::new(HashMap::new());
+        let group = StoredValue::new(None);
+        let initial: RwSignal<T> = RwSignal::new(values.clone());
         let values: RwSignal<T> = RwSignal::new(values);
         let errors = RwSignal::new(HashMap::new());
+        let form_errors = RwSignal::new(FieldErrors::new());
+        let parse_errors = RwSignal::new(HashMap::new());
+        let touched = RwSignal::new(HashSet::new());
+        let edited = RwSignal::new(HashSet::new());
+        let array_keys = RwSignal::new(HashMap::new());
+        let next_key = StoredValue::new(0);
+        let item_counts = StoredValue::new(HashMap::new());
+        let files = RwSignal::new_local(HashMap::new());
+        let file_rules = StoredValue::new(HashMap::new());
+        let submit_count = RwSignal::new(0);
+        let submitting = RwSignal::new(false);
+        let submit_successful = RwSignal::new(false);
+        let submit_error = RwSignal::new(None);
+        let validating = RwSignal::new(HashSet::new());
+        let async_validators = StoredValue::new(HashMap::new());
+        let async_runs = StoredValue::new(HashMap::new());
+        let async_errors = RwSignal::new(HashMap::new());
+        let queued_submit = StoredValue::new_local(None);
+        let messages = StoredValue::new(MessageResolver::default());
+        let translator = StoredValue::new(None);
+
+        Self {
+            validator,
+            groups,
+            group,
+            values,
+            errors,
+            form_errors,
+            parse_errors,
+            touched,
+            edited,
+            initial,
+            array_keys,
+            next_key,
+            item_counts,
+            files,
+            file_rules,
+            submit_count,
+            submitting,
+            submit_successful,
+            submit_error,
+            validating,
+            async_validators,
+            async_runs,
+            async_errors,
+            queued_submit,
+            messages,
+            translator,
+            locale: None,
+            mode: ValidationMode::default(),
+            revalidate_mode: ValidationMode::OnChange,
+        }
+    }
+
+    /// Sets the [`ValidationMode`] used before the form is submitted for the
+    /// first time. Defaults to [`ValidationMode::OnSubmit`].
+    pub fn with_mode(mut self, mode: ValidationMode) -> Form<T, V> {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the [`ValidationMode`] used after the form is submitted for the
+    /// first time. Defaults to [`ValidationMode::OnChange`].
+    pub fn with_revalidate_mode(mut self, mode: ValidationMode) -> Form<T, V> {
+        self.revalidate_mode = mode;
+        self
+    }
+
+    /// Sets the [`MessageResolver`] used to build error messages for errors
+    /// without a custom message
+    pub fn with_messages(self, resolver: MessageResolver) -> Form<T, V> {
+        self.messages.set_value(resolver);
+        self
+    }
+
+    /// Registers the message template used for errors with the provided
+    /// `code`, such as `"must be at least {min} characters"`
+    pub fn with_message_template(
+        self,
+        code: impl Into<Cow<'static, str>>,
+        template: impl Into<Cow<'static, str>>,
+    ) -> Form<T, V> {
+        self.messages
+            .update_value(|messages| messages.set_template(code, template));
+        self
+    }
+
+    /// Bounds the number of rows of the list field with the provided name
+    pub fn with_item_count(self, name: &str, count: ItemCount) -> Form<T, V> {
+        self.item_counts.update_value(|item_counts| {
+            item_counts.insert(name.to_string(), count);
+        });
+        self
+    }
 
-        Self { values, errors }
+    /// Registers a [`ValidationGroup`] under the provided name, to be picked
+    /// by submit handlers such as [`Form::handle_submit_group`]
+    pub fn with_validation_group(self, name: &str, group: ValidationGroup<T>) -> Form<T, V> {
+        self.groups.update_value(|groups| {
+            groups.insert(name.to_string(), group);
+        });
+        self
+    }
+
+    /// Sets the [`AsyncValidator`] run for the field with the provided name
+    pub fn with_async_validator(self, name: &str, validator: AsyncValidator) -> Form<T, V> {
+        self.async_validators.update_value(|async_validators| {
+            async_validators.insert(name.to_string(), validator);
+        });
+        self
+    }
+
+    /// Sets the [`FileRules`] checked for the file field with the provided
+    /// name
+    pub fn with_file_rules(self, name: &str, rules: FileRules) -> Form<T, V> {
+        self.file_rules.update_value(|file_rules| {
+            file_rules.insert(name.to_string(), rules);
+        });
+        self
+    }
+
+    /// Sets the [`MessageTranslator`] used to build error messages in the
+    /// locale held by the provided signal
+    pub fn with_translator(
+        mut self,
+        translator: impl MessageTranslator,
+        locale: impl Into<Signal<String>>,
+    ) -> Form<T, V> {
+        let translator: Arc<dyn MessageTranslator> = Arc::new(translator);
+
+        self.translator.set_value(Some(translator));
+        self.locale = Some(locale.into());
+        self
     }
 
     pub fn value(&self, field: &str) -> Signal<String> {
@@ -48,78 +354,1560 @@ impl<T: Clone + Default + FormStruct + Send + Sync + 'static> Form<T> {
         Memo::new(move |_| values.get().get(&field)).into()
     }
 
-    pub fn clear_field_value(&self, field: &str) {
+    /// Retrieves the typed value of a field
+    pub fn field_value(&self, field: &str) -> Signal<Option<FieldValue>> {
+        let field = field.to_string();
         let values = self.values;
 
-        values.update(|values| {
-            values.set(field, "");
-        });
+        Memo::new(move |_| values.get().get_value(&field)).into()
     }
 
-    pub fn set_field_value(&self, field: &str, value: Option<String>) {
+    /// Retrieves the value of a field converted into `U`, or `None` if the
+    /// field is missing or its value cannot be converted.
+    pub fn value_as<U>(&self, field: &str) -> Signal<Option<U>>
+    where
+        U: FromFieldValue + Clone + PartialEq + Send + Sync + 'static,
+    {
+        let field = field.to_string();
         let values = self.values;
 
-        values.update(|values| {
-            values.set(field, &value.unwrap_or_default());
+        Memo::new(move |_| {
+            values
+                .get()
+                .get_value(&field)
+                .and_then(|value| U::from_field_value(value).ok())
+        })
+        .into()
+    }
+
+    /// Writes a typed value into a field.
+    ///
+    /// If the value cannot be converted into the field type, the field is left
+    /// untouched and the parse error is stored as the field error.
+    pub fn set_value<U: IntoFieldValue + ?Sized>(&self, field: &str, value: &U) {
+        let value = value.to_field_value();
+        let result = self
+            .values
+            .try_update(|values| values.set_value(field, value));
+
+        self.record_parse_result(field, result);
+    }
+
+    /// Writes an empty text value into a field.
+    ///
+    /// If the field type cannot hold an empty value, such as a number, the
+    /// field is left untouched and the parse error is stored as the field
+    /// error.
+    pub fn clear_field_value(&self, field: &str) {
+        self.write_field(field, "");
+    }
+
+    /// Writes the text value of a field.
+    ///
+    /// If the text cannot be parsed into the field type, the field is left
+    /// untouched and the parse error is stored as the field error.
+    pub fn set_field_value(&self, field: &str, value: Option<String>) {
+        self.write_field(field, &value.unwrap_or_default());
+    }
+
+    /// Retrieves the files selected for a file field
+    pub fn files(&self, field: &str) -> Signal<Vec<File>, LocalStorage> {
+        let field = field.to_string();
+        let files = self.files;
+
+        Signal::derive_local(move || {
+            files.with(|files| files.get(&field).cloned().unwrap_or_default())
+        })
+    }
+
+    pub fn set_files(&self, field: &str, files: Vec<File>) {
+        self.files.update(|f| {
+            f.insert(field.to_string(), files);
+        });
+        self.mark_edited(field);
+        self.clear_error(field);
+    }
+
+    pub fn clear_files(&self, field: &str) {
+        self.files.update(|f| {
+            f.remove(field);
         });
+        self.mark_edited(field);
+        self.clear_error(field);
     }
 
+    /// Serialises the form values and selected files into [`FormData`].
+    ///
+    /// Every field listed by [`FormStruct::field_names`] is appended as
+    /// text, with list values appended once per item.
+    pub fn form_data(&self) -> Result<FormData, JsValue> {
+        let data = FormData::new()?;
+
+        self.values.with_untracked(|values| {
+            for name in values.field_names() {
+                match values.get_value(&name) {
+                    Some(FieldValue::List(items)) => {
+                        for item in items {
+                            data.append_with_str(&name, &item.to_string())?;
+                        }
+                    }
+                    Some(FieldValue::Null) | None => {}
+                    Some(value) => data.append_with_str(&name, &value.to_string())?,
+                }
+            }
+
+            Ok::<_, JsValue>(())
+        })?;
+
+        self.files.with_untracked(|files| {
+            for (name, files) in files {
+                for file in files {
+                    data.append_with_blob_and_filename(name, file, &file.name())?;
+                }
+            }
+
+            Ok::<_, JsValue>(())
+        })?;
+
+        Ok(data)
+    }
+
+    /// Number of times the form was submitted, whether validation passed or
+    /// not
+    pub fn submit_count(&self) -> Signal<usize> {
+        self.submit_count.into()
+    }
+
+    /// Error returned by the last submission made through
+    /// [`Form::handle_submit_async`], cleared on the next submission
+    pub fn submit_error(&self) -> Signal<Option<String>> {
+        self.submit_error.into()
+    }
+
+    /// Retrieves the reactive [`FormState`] of the form
+    pub fn state(&self) -> FormState {
+        let form = *self;
+        let submit_count = self.submit_count;
+        let validating = self.validating;
+
+        let is_valid = Memo::new(move |_| {
+            form.values.track();
+            form.files.track();
+            form.parse_errors.track();
+            form.async_errors.track();
+
+            form.errors.with(|errors| errors.is_empty())
+                && form.form_errors.with(|errors| errors.is_empty())
+                && validating.with(|v| v.is_empty())
+                && !form.has_unchecked_values()
+                && form.collect_errors().is_empty()
+        });
+
+        FormState {
+            is_valid: is_valid.into(),
+            is_validating: Memo::new(move |_| validating.with(|v| !v.is_empty())).into(),
+            is_submitting: self.submitting.into(),
+            is_submitted: Memo::new(move |_| submit_count.get() > 0).into(),
+            is_submit_successful: self.submit_successful.into(),
+            submit_count: submit_count.into(),
+            submit_error: self.submit_error.into(),
+            is_dirty: self.is_form_dirty(),
+        }
+    }
+
+    /// Restores the values the form was built with, or last reset to
+    pub fn reset(&self, options: ResetOptions) {
+        let initial = self.initial.get_untracked();
+
+        self.restore(initial, options);
+    }
+
+    /// Replaces the form values with `values`, which also become the initial
+    /// values used to compute dirty state unless
+    /// [`ResetOptions::keep_initial_values`] is set
+    pub fn reset_to(&self, values: T, options: ResetOptions) {
+        if !options.keep_initial_values {
+            self.initial.set(values.clone());
+        }
+
+        self.restore(values, options);
+    }
+
+    /// Restores the initial value of a single field, along with its errors
+    /// and touched state unless kept by the options.
+    ///
+    /// Fields holding lists or nested values, such as `items`, are restored
+    /// as a whole, along with the state of the fields nested in them.
+    pub fn reset_field(&self, field: &str, options: ResetOptions) {
+        let within = |name: &str| is_within(name, field);
+
+        self.initial.with_untracked(|initial| {
+            self.values
+                .update(|values| restore_field(values, initial, field));
+            self.regenerate_keys(initial, within);
+        });
+
+        self.files
+            .update(|files| files.retain(|name, _| !within(name)));
+        self.cancel_async_validation(within);
+
+        if !options.keep_errors {
+            self.parse_errors
+                .update(|e| e.retain(|name, _| !within(name)));
+            self.errors.update(|e| e.retain(|name, _| !within(name)));
+        }
+
+        if !options.keep_touched {
+            self.touched
+                .update(|touched| touched.retain(|name| !within(name)));
+            self.edited
+                .update(|edited| edited.retain(|name| !within(name)));
+        }
+    }
+
+    /// Whether the field lost focus at least once
+    pub fn is_touched(&self, field: &str) -> Signal<bool> {
+        let field = field.to_string();
+        let touched = self.touched;
+
+        Memo::new(move |_| touched.with(|touched| touched.contains(&field))).into()
+    }
+
+    /// Drops the results cached by the [`AsyncValidator`] of a field, so the
+    /// next validation of the field runs the check again
+    pub fn invalidate_async_cache(&self, field: &str) {
+        if let Some(validator) = self
+            .async_validators
+            .with_value(|async_validators| async_validators.get(field).cloned())
+        {
+            validator.invalidate();
+        }
+
+        self.async_runs.update_value(|runs| {
+            if let Some(run) = runs.get_mut(field) {
+                run.value = None;
+            }
+        });
+    }
+
+    /// Whether the [`AsyncValidator`] of the field is running, or waiting
+    /// for its debounce delay
+    pub fn is_validating(&self, field: &str) -> Signal<bool> {
+        let field = field.to_string();
+        let validating = self.validating;
+
+        Memo::new(move |_| validating.with(|v| v.contains(&field))).into()
+    }
+
+    /// Whether the value of the field differs from its initial value
+    pub fn is_dirty(&self, field: &str) -> Signal<bool> {
+        let field = field.to_string();
+        let form = *self;
+
+        Memo::new(move |_| form.field_is_dirty(&field)).into()
+    }
+
+    /// Whether the value of any field differs from its initial value
+    pub fn is_form_dirty(&self) -> Signal<bool> {
+        let dirty_fields = self.dirty_fields();
+
+        Memo::new(move |_| dirty_fields.with(|fields| !fields.is_empty())).into()
+    }
+
+    /// Lists the fields whose value differs from their initial value
+    pub fn dirty_fields(&self) -> Signal<Vec<String>> {
+        let form = *self;
+
+        Memo::new(move |_| {
+            let mut fields: Vec<String> = form
+                .values
+                .with(|values| {
+                    form.initial.with(|initial| {
+                        form.edited.with(|edited| {
+                            values
+                                .field_names()
+                                .into_iter()
+                                .chain(initial.field_names())
+                                .chain(edited.iter().cloned())
+                                .collect::<HashSet<_>>()
+                        })
+                    })
+                })
+                .into_iter()
+                .filter(|field| form.field_is_dirty(field))
+                .collect();
+
+            fields.sort();
+            fields
+        })
+        .into()
+    }
+
+    /// Retrieves the list field with the provided name as a [`FieldArray`]
+    /// of rows of type `R`
+    pub fn field_array<R: Send + Sync + 'static>(&self, name: &str) -> FieldArray<T, R, V> {
+        FieldArray::new(*self, name)
+    }
+
+    /// Retrieves the message of the first error of a field
     pub fn error(&self, field: &str) -> Signal<Option<String>> {
         let field = field.to_string();
+        let form = *self;
+
+        Memo::new(move |_| {
+            form.errors.with(|e| {
+                e.get(&field)
+                    .and_then(FieldErrors::first)
+                    .map(|err| form.message(err))
+            })
+        })
+        .into()
+    }
+
+    /// Retrieves the messages of every error of a field
+    pub fn error_messages(&self, field: &str) -> Signal<Vec<String>> {
+        let field = field.to_string();
+        let form = *self;
+
+        Memo::new(move |_| {
+            form.errors.with(|e| {
+                e.get(&field)
+                    .map(|f_errors| f_errors.iter().map(|err| form.message(err)).collect())
+                    .unwrap_or_default()
+            })
+        })
+        .into()
+    }
+
+    /// Builds the display text of an error.
+    ///
+    /// The form [`MessageTranslator`] is used first, for the current locale,
+    /// falling back to the form [`MessageResolver`]. Reading the locale is
+    /// tracked, so messages built inside reactive scopes update when the
+    /// locale changes.
+    pub fn message(&self, err: &FieldError) -> String {
+        if let Some(locale) = self.locale {
+            let translated = self.translator.with_value(|translator| {
+                translator
+                    .as_ref()
+                    .and_then(|translator| locale.with(|locale| translator.translate(locale, err)))
+            });
+
+            if let Some(translated) = translated {
+                return translated;
+            }
+        }
+
+        self.messages.with_value(|messages| messages.resolve(err))
+    }
+
+    /// Retrieves every error of a field
+    pub fn errors_for(&self, field: &str) -> Signal<Vec<FieldError>> {
+        let field = field.to_string();
+        let errors = self.errors;
+
+        Memo::new(move |_| {
+            errors.with(|e| {
+                e.get(&field)
+                    .map(|f_errors| f_errors.as_slice().to_vec())
+                    .unwrap_or_default()
+            })
+        })
+        .into()
+    }
+
+    /// Sets the error message of a field, replacing any existing error
+    pub fn set_error(&self, field: &str, message: impl Into<String>) {
+        let error = FieldError::new("custom").with_message(message.into());
+
+        self.errors.update(|e| {
+            e.insert(field.to_string(), FieldErrors::from(vec![error]));
+        });
+    }
+
+    /// Removes the errors of a single field
+    pub fn clear_error(&self, field: &str) {
+        self.cancel_async_validation(|name| name == field);
+        self.parse_errors.update(|e| {
+            e.remove(field);
+        });
+        self.errors.update(|e| {
+            e.remove(field);
+        });
+    }
+
+    /// Removes the errors of every field, along with form-level errors
+    pub fn clear_errors(&self) {
+        self.cancel_async_validation(|_| true);
+        self.parse_errors.update(|e| e.clear());
+        self.errors.update(|e| e.clear());
+        self.form_errors.update(|e| *e = FieldErrors::new());
+    }
+
+    /// Merges errors reported by the server, such as "email already taken",
+    /// into the form errors.
+    ///
+    /// Field errors are cleared once their field is edited, while form-level
+    /// errors are cleared on the next submission.
+    pub fn set_server_errors(&self, errors: FormErrors) {
+        let FormErrors { fields, form } = errors;
+
+        self.errors.update(|e| {
+            for (field, f_errors) in fields {
+                if !f_errors.is_empty() {
+                    e.entry(field).or_default().extend(f_errors);
+                }
+            }
+        });
+        self.form_errors.update(|e| e.extend(form));
+    }
+
+    /// Retrieves the messages of the errors of the form as a whole
+    pub fn form_error_messages(&self) -> Signal<Vec<String>> {
+        let form = *self;
+
+        Memo::new(move |_| {
+            form.form_errors
+                .with(|e| e.iter().map(|err| form.message(err)).collect())
+        })
+        .into()
+    }
+
+    /// Retrieves every error of the form, including form-level errors
+    pub fn errors(&self) -> Signal<FormErrors> {
         let errors = self.errors;
+        let form_errors = self.form_errors;
+
+        Memo::new(move |_| FormErrors {
+            fields: errors.get(),
+            form: form_errors.get(),
+        })
+        .into()
+    }
+
+    /// Validates the fields with the provided names, along with the fields
+    /// nested in them, such as `shipping.city` in `shipping`, replacing their
+    /// errors with the outcome and leaving the errors of every other field
+    /// alone.
+    ///
+    /// Returns whether none of the fields has errors. Checks run by
+    /// [`AsyncValidator`]s settle later, see [`Form::is_validating`].
+    ///
+    /// Once the form is submitted using a [`ValidationGroup`], fields outside
+    /// of the group are left alone until the next submission.
+    pub fn trigger(&self, fields: &[&str]) -> bool {
+        let group = self.active_group();
+        let next = self
+            .values
+            .with_untracked(|values| self.run_validator(values));
+        let within = |path: &str| fields.iter().any(|field| is_within(path, field));
+
+        let mut paths: HashSet<String> = fields.iter().map(ToString::to_string).collect();
+        paths.extend(next.fields.keys().filter(|path| within(path)).cloned());
+        paths.extend(
+            self.check_rules(None)
+                .into_keys()
+                .filter(|path| within(path)),
+        );
+        self.errors.with_untracked(|errors| {
+            paths.extend(errors.keys().filter(|path| within(path)).cloned());
+        });
+        self.async_validators.with_value(|async_validators| {
+            paths.extend(async_validators.keys().filter(|path| within(path)).cloned());
+        });
 
-        Memo::new(move |_| errors.get().get(&field).cloned().flatten()).into()
+        for path in &paths {
+            if group.as_ref().is_some_and(|group| !group.covers(path)) {
+                continue;
+            }
+
+            let f_errors = next.field(path).cloned().unwrap_or_default();
+            self.replace_field_errors(path, f_errors);
+        }
+
+        self.errors
+            .with_untracked(|errors| !errors.keys().any(|path| within(path)))
     }
 
-    /// Input Handler for Form Inputs of type [`HtmlInputElement`]
+    /// Validates a single field, see [`Form::trigger`]
+    pub fn validate_field(&self, field: &str) -> bool {
+        self.trigger(&[field])
+    }
+
+    /// Input Handler for Form Inputs of type [`HtmlInputElement`],
+    /// [`HtmlSelectElement`] and [`HtmlTextAreaElement`]
+    ///
+    /// - Checkboxes write whether they are checked, unless the field holds a
+    ///   list, in which case the checkbox value is added to or removed from it
+    /// - Radio buttons write the value of the checked one
+    /// - `<select multiple>` elements write the list of selected values
+    /// - File inputs keep the selected files, see [`Form::files`]
+    ///
+    /// [`HtmlInputElement`]: web_sys::HtmlInputElement
+    /// [`HtmlSelectElement`]: web_sys::HtmlSelectElement
+    /// [`HtmlTextAreaElement`]: web_sys::HtmlTextAreaElement
     pub fn handle_input(&self) -> impl Fn(Event) + Copy + 'static {
-        let values = self.values;
+        let form = *self;
 
         move |ev: Event| {
-            if let Some(target) = ev.target() {
-                if let Ok(el) = target.dyn_into::<HtmlInputElement>() {
-                    let name = el.name();
+            let Some(target) = ev.target() else {
+                return;
+            };
 
-                    values.update(|values| {
-                        let value = event_target_value(&ev);
-                        values.set(&name, &value);
-                    });
+            let Some(name) = field_name(&target) else {
+                return;
+            };
+
+            let is_list = form.values.with_untracked(|values| {
+                matches!(values.get_value(&name), Some(FieldValue::List(_)))
+            });
+
+            let written = match read_input(&target, is_list) {
+                Some(InputValue::Set(value)) => form.write_value(&name, value),
+                Some(InputValue::Toggle { value, checked }) => {
+                    form.write_value(&name, form.toggle(&name, value, checked))
+                }
+                Some(InputValue::Files(files)) => {
+                    form.set_files(&name, files);
+                    true
                 }
+                Some(InputValue::Ignore) | None => false,
+            };
+
+            if written
+                && form
+                    .active_mode()
+                    .validates_on_change(form.touched.with_untracked(|t| t.contains(&name)))
+            {
+                form.validate_field(&name);
             }
         }
     }
 
-    pub fn handle_submit<F: Fn(T)>(&self, cb: F) -> impl Fn(SubmitEvent) {
-        let errors = self.errors;
-        let values = self.values;
+    /// Blur Handler for the same form controls as [`Form::handle_input`]
+    ///
+    /// Marks the field as touched and validates it if the current
+    /// [`ValidationMode`] validates on blur.
+    pub fn handle_blur(&self) -> impl Fn(FocusEvent) + Copy + 'static {
+        let form = *self;
+
+        move |ev: FocusEvent| {
+            if let Some(target) = ev.target()
+                && let Some(name) = field_name(&target)
+            {
+                form.touched.update(|touched| {
+                    touched.insert(name.clone());
+                });
+
+                if form.active_mode().validates_on_blur() {
+                    form.validate_field(&name);
+                }
+            }
+        }
+    }
+
+    /// Submit Handler validating the form and providing its values
+    ///
+    /// Submissions made while [`AsyncValidator`]s are pending complete once
+    /// the checks settle.
+    pub fn handle_submit<F: Fn(T) + 'static>(&self, cb: F) -> impl Fn(SubmitEvent) {
+        let form = *self;
+        let cb = Rc::new(cb);
 
         move |ev| {
             ev.prevent_default();
 
-            if let Err(validation_err) = values.get().validate() {
-                validation_err
-                    .field_errors()
+            let cb = Rc::clone(&cb);
+            form.submit(None, move |values| {
+                cb(values);
+                true
+            });
+        }
+    }
+
+    /// Submit Handler validating only the [`ValidationGroup`] registered
+    /// under the provided name, e.g. to save a draft
+    ///
+    /// Errors outside of the group are not reported.
+    pub fn handle_submit_group<F: Fn(T) + 'static>(
+        &self,
+        group: &str,
+        cb: F,
+    ) -> impl Fn(SubmitEvent) {
+        let form = *self;
+        let group = group.to_string();
+        let cb = Rc::new(cb);
+
+        move |ev| {
+            ev.prevent_default();
+
+            let cb = Rc::clone(&cb);
+            form.submit(Some(&group), move |values| {
+                cb(values);
+                true
+            });
+        }
+    }
+
+    /// Submit Handler for forms with file inputs
+    ///
+    /// Validates the form like [`Form::handle_submit`] does and provides the
+    /// values and selected files serialised into [`FormData`].
+    pub fn handle_submit_form_data<F: Fn(FormData) + 'static>(
+        &self,
+        cb: F,
+    ) -> impl Fn(SubmitEvent) {
+        let form = *self;
+        let cb = Rc::new(cb);
+
+        move |ev| {
+            ev.prevent_default();
+
+            let cb = Rc::clone(&cb);
+            form.submit(None, move |_| match form.form_data() {
+                Ok(data) => {
+                    cb(data);
+                    true
+                }
+                Err(err) => {
+                    leptos::logging::error!("failed to build form data: {err:?}");
+                    false
+                }
+            });
+        }
+    }
+
+    /// Submit handler for asynchronous submissions, such as calls to server
+    /// functions.
+    ///
+    /// The form is marked as submitting until the future returned by `cb`
+    /// resolves, and submits made in the meantime are ignored. The error
+    /// returned by `cb`, if any, is available through [`Form::submit_error`].
+    pub fn handle_submit_async<F, Fut, E>(&self, cb: F) -> impl Fn(SubmitEvent)
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display,
+    {
+        let form = *self;
+        let cb = Rc::new(cb);
+
+        move |ev| {
+            ev.prevent_default();
+
+            let cb = Rc::clone(&cb);
+            form.submit_async(None, move |values| cb(values));
+        }
+    }
+
+    /// Asynchronous submit handler validating only the [`ValidationGroup`]
+    /// registered under the provided name, see
+    /// [`Form::handle_submit_async`] and [`Form::handle_submit_group`]
+    pub fn handle_submit_async_group<F, Fut, E>(&self, group: &str, cb: F) -> impl Fn(SubmitEvent)
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display,
+    {
+        let form = *self;
+        let group = group.to_string();
+        let cb = Rc::new(cb);
+
+        move |ev| {
+            ev.prevent_default();
+
+            let cb = Rc::clone(&cb);
+            form.submit_async(Some(&group), move |values| cb(values));
+        }
+    }
+
+    /// Counts a submission and validates the form, running `submit` with
+    /// the form values once they are valid.
+    ///
+    /// `submit` returns whether the submission succeeded.
+    fn submit(&self, group: Option<&str>, submit: impl FnOnce(T) -> bool + 'static) {
+        let form = *self;
+
+        self.begin_submit(group, move |values| {
+            let successful = submit(values);
+            form.finish_submit(successful);
+        });
+    }
+
+    /// Like [`Form::submit`], awaiting the future returned by `submit` and
+    /// keeping its error
+    fn submit_async<Fut, E>(&self, group: Option<&str>, submit: impl FnOnce(T) -> Fut + 'static)
+    where
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display,
+    {
+        let form = *self;
+
+        self.begin_submit(group, move |values| {
+            let fut = submit(values);
+
+            leptos::task::spawn_local(async move {
+                let result = fut.await;
+
+                if let Err(err) = &result {
+                    form.submit_error.set(Some(err.to_string()));
+                }
+
+                form.finish_submit(result.is_ok());
+            });
+        });
+    }
+
+    /// Counts a submission and queues `submit`, to be run with the form
+    /// values once they are valid, see [`Form::settle_submit`].
+    ///
+    /// Only the [`ValidationGroup`] registered under `group` is validated, if
+    /// provided. Submissions made while another one is in flight are
+    /// ignored, without being counted.
+    fn begin_submit(&self, group: Option<&str>, submit: impl FnOnce(T) + 'static) {
+        if self.submitting.get_untracked() {
+            return;
+        }
+
+        let group = group.filter(|group| {
+            let known = self.groups.with_value(|groups| groups.contains_key(*group));
+
+            if !known {
+                leptos::logging::warn!(
+                    "unknown validation group `{group}`, validating every field"
+                );
+            }
+
+            known
+        });
+
+        self.group.set_value(group.map(ToString::to_string));
+        self.submit_count.update(|count| *count += 1);
+        self.submit_successful.set(false);
+        self.submit_error.set(None);
+        self.queued_submit.set_value(Some(Box::new(submit)));
+        self.settle_submit();
+    }
+
+    /// Validates the form for the queued submission, marking the form as
+    /// submitting and running the submission when the values are valid, and
+    /// dropping it when they are not.
+    ///
+    /// While [`AsyncValidator`]s are pending, the submission stays queued
+    /// and the form is marked as submitting, until their checks settle.
+    fn settle_submit(&self) {
+        if self
+            .queued_submit
+            .try_with_value(Option::is_none)
+            .unwrap_or(true)
+        {
+            return;
+        }
+
+        let valid = self.validate_errors();
+        let pending = self.run_unchecked_async_validators();
+        let valid = valid && self.errors.with_untracked(|errors| errors.is_empty());
+
+        if valid && pending {
+            if !self.submitting.get_untracked() {
+                self.submitting.set(true);
+            }
+
+            return;
+        }
+
+        let Some(submit) = self.queued_submit.try_update_value(Option::take).flatten() else {
+            return;
+        };
+
+        if !valid {
+            if self.submitting.get_untracked() {
+                self.submitting.set(false);
+            }
+
+            return;
+        }
+
+        if !self.submitting.get_untracked() {
+            self.submitting.set(true);
+        }
+
+        submit(self.values.get_untracked());
+    }
+
+    /// Settles the queued submission, if any, once no [`AsyncValidator`] is
+    /// pending.
+    ///
+    /// The submission is settled in a new task, so it does not run in the
+    /// middle of the change that settled the checks.
+    fn resume_submit(&self) {
+        let waiting = self
+            .queued_submit
+            .try_with_value(Option::is_some)
+            .unwrap_or_default()
+            && self.validating.with_untracked(HashSet::is_empty);
+
+        if waiting {
+            let form = *self;
+
+            leptos::task::spawn_local(async move { form.settle_submit() });
+        }
+    }
+
+    fn finish_submit(&self, successful: bool) {
+        self.submitting.set(false);
+        self.submit_successful.set(successful);
+    }
+
+    /// Writes the text value of a field, returning whether it was parsed
+    /// successfully.
+    fn write_field(&self, field: &str, value: &str) -> bool {
+        let result = self
+            .values
+            .try_update(|values| values.try_set(field, value));
+        let written = matches!(result, Some(Ok(())));
+
+        self.record_parse_result(field, result);
+        written
+    }
+
+    /// Writes the value of a field, returning whether it was converted into
+    /// the field type successfully.
+    fn write_value(&self, field: &str, value: FieldValue) -> bool {
+        if let FieldValue::Text(text) = &value {
+            return self.write_field(field, text);
+        }
+
+        let result = self
+            .values
+            .try_update(|values| values.set_value(field, value));
+        let written = matches!(result, Some(Ok(())));
+
+        self.record_parse_result(field, result);
+        written
+    }
+
+    /// Builds the list value of a field with `value` added to or removed
+    /// from it
+    fn toggle(&self, field: &str, value: String, checked: bool) -> FieldValue {
+        let mut items = match self.values.with_untracked(|values| values.get_value(field)) {
+            Some(FieldValue::List(items)) => items,
+            _ => Vec::new(),
+        };
+
+        items.retain(|item| item.to_string() != value);
+
+        if checked {
+            items.push(FieldValue::Text(value));
+        }
+
+        FieldValue::List(items)
+    }
+
+    /// The [`ValidationMode`] in effect, depending on whether the form was
+    /// already submitted.
+    fn active_mode(&self) -> ValidationMode {
+        if self.submit_count.get_untracked() > 0 {
+            self.revalidate_mode
+        } else {
+            self.mode
+        }
+    }
+
+    /// Validates the form values and replaces the error state with the
+    /// outcome, returning whether the values are valid.
+    fn validate_errors(&self) -> bool {
+        let FormErrors { fields, form } = self.collect_errors();
+        let valid = fields.is_empty() && form.is_empty();
+
+        self.errors.set(fields);
+        self.form_errors.set(form);
+        valid
+    }
+
+    /// The [`ValidationGroup`] picked by the last submission, if any
+    fn active_group(&self) -> Option<ValidationGroup<T>> {
+        let name = self.group.get_value()?;
+
+        self.groups.with_value(|groups| groups.get(&name).cloned())
+    }
+
+    /// Checks the values using the [`FormValidator`] of the active
+    /// [`ValidationGroup`], falling back to the one of the form
+    fn run_validator(&self, values: &T) -> FormErrors {
+        let group = self.active_group();
+        let errors = match group.as_ref().and_then(ValidationGroup::form_validator) {
+            Some(validator) => validator.validate(values).err(),
+            None => self
+                .validator
+                .with_value(|validator| validator.validate(values).err()),
+        };
+
+        errors.unwrap_or_default()
+    }
+
+    /// Collects the errors of every field: parse errors, validation errors
+    /// and violated [`ItemCount`] and [`FileRules`], along with form-level
+    /// validation errors.
+    ///
+    /// Fields holding input that could not be parsed keep their parse error,
+    /// while fields rejected by their [`AsyncValidator`] keep its error.
+    /// Errors outside of the active [`ValidationGroup`] are left out.
+    fn collect_errors(&self) -> FormErrors {
+        let mut next = self
+            .values
+            .with_untracked(|values| self.run_validator(values));
+
+        for (field, f_errors) in self.check_rules(None) {
+            next.fields.entry(field).or_default().extend(f_errors);
+        }
+
+        self.parse_errors.with_untracked(|parse_errors| {
+            for (field, err) in parse_errors {
+                let f_errors = next.fields.remove(field).unwrap_or_default();
+
+                next.fields.insert(
+                    field.to_string(),
+                    std::iter::once(err.clone()).chain(f_errors).collect(),
+                );
+            }
+        });
+
+        self.async_errors.with_untracked(|async_errors| {
+            for (field, err) in async_errors {
+                next.fields
+                    .entry(field.to_string())
+                    .or_default()
+                    .push(err.clone());
+            }
+        });
+
+        if let Some(group) = self.active_group() {
+            group.retain(&mut next);
+        }
+
+        next
+    }
+
+    /// Replaces the errors of a single field with its validation errors,
+    /// along with its parse error and violated [`ItemCount`] and
+    /// [`FileRules`].
+    ///
+    /// Once the field passes synchronous validation, its [`AsyncValidator`]
+    /// is run as well.
+    fn replace_field_errors(&self, field: &str, f_errors: FieldErrors) {
+        let parse_error = self
+            .parse_errors
+            .with_untracked(|parse_errors| parse_errors.get(field).cloned());
+        let rules = self
+            .check_rules(Some(field))
+            .remove(field)
+            .unwrap_or_default();
+        let f_errors: FieldErrors = parse_error
+            .into_iter()
+            .chain(f_errors)
+            .chain(rules)
+            .collect();
+
+        if !f_errors.is_empty() {
+            self.cancel_async_validation(|name| name == field);
+            self.errors.update(|e| {
+                e.insert(field.to_string(), f_errors);
+            });
+            return;
+        }
+
+        let async_error = self
+            .async_errors
+            .with_untracked(|async_errors| async_errors.get(field).cloned());
+
+        self.errors.update(|e| match async_error {
+            Some(err) => {
+                e.insert(field.to_string(), FieldErrors::from(vec![err]));
+            }
+            None => {
+                e.remove(field);
+            }
+        });
+        self.run_async_validator(field, true);
+    }
+
+    /// Starts the [`AsyncValidator`] of every field that passed synchronous
+    /// validation and has no result for its current value, skipping the
+    /// debounce delay.
+    ///
+    /// Returns whether any check is pending.
+    fn run_unchecked_async_validators(&self) -> bool {
+        let group = self.active_group();
+        let fields: Vec<String> = self
+            .async_validators
+            .with_value(|async_validators| async_validators.keys().cloned().collect());
+
+        for field in fields {
+            let skip = group.as_ref().is_some_and(|group| !group.covers(&field))
+                || self
+                    .errors
+                    .with_untracked(|errors| errors.contains_key(&field));
+
+            if !skip {
+                self.run_async_validator(&field, false);
+            }
+        }
+
+        self.validating.with_untracked(|v| !v.is_empty())
+    }
+
+    /// Whether the value of any field with an [`AsyncValidator`] was not
+    /// checked yet
+    fn has_unchecked_values(&self) -> bool {
+        self.async_validators.with_value(|async_validators| {
+            self.async_runs.with_value(|runs| {
+                self.values.with_untracked(|values| {
+                    async_validators.keys().any(|field| {
+                        let value = values.get_value(field).unwrap_or_default();
+
+                        runs.get(field).and_then(|run| run.value.as_ref()) != Some(&value)
+                    })
+                })
+            })
+        })
+    }
+
+    /// Runs the [`AsyncValidator`] of a field, after its debounce delay if
+    /// `debounce` is set, unless the current value of the field was already
+    /// checked.
+    ///
+    /// Values with a cached result are settled right away.
+    fn run_async_validator(&self, field: &str, debounce: bool) {
+        let Some(validator) = self
+            .async_validators
+            .with_value(|async_validators| async_validators.get(field).cloned())
+        else {
+            return;
+        };
+
+        let value = self
+            .values
+            .with_untracked(|values| values.get_value(field))
+            .unwrap_or_default();
+        let generation = self
+            .async_runs
+            .try_update_value(|runs| {
+                let run = runs.entry(field.to_string()).or_default();
+
+                if run.value.as_ref() == Some(&value) {
+                    return None;
+                }
+
+                if let Some(timeout) = run.timeout.take() {
+                    timeout.clear();
+                }
+
+                run.generation += 1;
+                run.value = Some(value.clone());
+                Some(run.generation)
+            })
+            .flatten();
+
+        let Some(generation) = generation else {
+            return;
+        };
+
+        if let Some(error) = validator.cached(&value) {
+            self.finish_async_validation(field, generation, error);
+            return;
+        }
+
+        self.validating.update(|v| {
+            v.insert(field.to_string());
+        });
+
+        let form = *self;
+        let name = field.to_string();
+        let delay = validator.delay();
+        let run = move || {
+            let fut = validator.validate(value.clone());
+
+            leptos::task::spawn_local(async move {
+                let error = fut.await.err();
+
+                validator.store(value, error.clone());
+                form.finish_async_validation(&name, generation, error);
+            });
+        };
+
+        // Timers are only available in the browser
+        if !debounce || cfg!(not(target_arch = "wasm32")) {
+            run();
+            return;
+        }
+
+        match set_timeout_with_handle(run, delay) {
+            Ok(timeout) => self.async_runs.update_value(|runs| {
+                if let Some(run) = runs.get_mut(field)
+                    && run.generation == generation
+                {
+                    run.timeout = Some(timeout);
+                }
+            }),
+            Err(err) => {
+                leptos::logging::error!("failed to schedule async validation: {err:?}");
+                self.cancel_async_validation(|name| name == field);
+            }
+        }
+    }
+
+    /// Stores the outcome of a run of the [`AsyncValidator`] of a field,
+    /// unless a later run replaced it
+    fn finish_async_validation(&self, field: &str, generation: u64, error: Option<FieldError>) {
+        let current = self
+            .async_runs
+            .try_update_value(|runs| match runs.get_mut(field) {
+                Some(run) if run.generation == generation => {
+                    run.timeout = None;
+                    true
+                }
+                _ => false,
+            });
+
+        if current != Some(true) {
+            return;
+        }
+
+        self.validating.update(|v| {
+            v.remove(field);
+        });
+
+        let previous = self
+            .async_errors
+            .try_update(|e| match &error {
+                Some(err) => e.insert(field.to_string(), err.clone()),
+                None => e.remove(field),
+            })
+            .flatten();
+
+        self.errors.update(|e| {
+            let f_errors = e.entry(field.to_string()).or_default();
+
+            if let Some(previous) = &previous {
+                f_errors.retain(|err| err != previous);
+            }
+
+            f_errors.extend(error);
+
+            if f_errors.is_empty() {
+                e.remove(field);
+            }
+        });
+        self.resume_submit();
+    }
+
+    /// Drops the pending runs and results of the [`AsyncValidator`]s of the
+    /// fields for which `matches` returns `true`
+    pub(crate) fn cancel_async_validation(&self, matches: impl Fn(&str) -> bool) {
+        self.async_runs.update_value(|runs| {
+            for (name, run) in runs.iter_mut() {
+                if !matches(name) {
+                    continue;
+                }
+
+                if let Some(timeout) = run.timeout.take() {
+                    timeout.clear();
+                }
+
+                run.generation += 1;
+                run.value = None;
+            }
+        });
+
+        let is_pending = |name: &String| matches(name);
+
+        if self.validating.with_untracked(|v| v.iter().any(is_pending)) {
+            self.validating
+                .update(|v| v.retain(|name| !is_pending(name)));
+            self.resume_submit();
+        }
+
+        if self
+            .async_errors
+            .with_untracked(|e| e.keys().any(is_pending))
+        {
+            self.async_errors
+                .update(|e| e.retain(|name, _| !is_pending(name)));
+        }
+    }
+
+    /// Checks the [`ItemCount`] and [`FileRules`] of every field, or only the
+    /// ones of the field with the provided name
+    fn check_rules(&self, name: Option<&str>) -> HashMap<String, FieldErrors> {
+        let mut out = self.check_item_counts(name);
+
+        self.file_rules.with_value(|file_rules| {
+            self.files.with_untracked(|files| {
+                for (field, rules) in file_rules {
+                    if name.is_some_and(|name| name != field) {
+                        continue;
+                    }
+
+                    let f_errors =
+                        rules.check(files.get(field).map(Vec::as_slice).unwrap_or_default());
+
+                    if !f_errors.is_empty() {
+                        out.entry(field.to_string()).or_default().extend(f_errors);
+                    }
+                }
+            });
+        });
+
+        out
+    }
+
+    /// Checks the [`ItemCount`] of every list field, or only the one with
+    /// the provided name
+    fn check_item_counts(&self, name: Option<&str>) -> HashMap<String, FieldErrors> {
+        self.item_counts.with_value(|item_counts| {
+            self.values.with_untracked(|values| {
+                item_counts
                     .iter()
-                    .for_each(|(field, f_errors)| {
-                        f_errors.iter().for_each(|err| {
-                            errors.update(|e| {
-                                e.insert(
-                                    field.to_string(),
-                                    Some(
-                                        err.message
-                                            .clone()
-                                            .map(|m| m.to_string())
-                                            .unwrap_or_default(),
-                                    ),
-                                );
-                            });
-                        });
-                    });
+                    .filter(|(field, _)| name.is_none_or(|name| name == field.as_str()))
+                    .map(|(field, count)| (field.to_string(), count.check(list_len(values, field))))
+                    .filter(|(_, f_errors)| !f_errors.is_empty())
+                    .collect()
+            })
+        })
+    }
 
-                return;
+    /// Replaces the [`ItemCount`] errors of a list field, leaving its other
+    /// errors in place
+    fn validate_item_count(&self, name: &str) {
+        let f_errors = self
+            .check_item_counts(Some(name))
+            .remove(name)
+            .unwrap_or_default();
+
+        self.errors.update(|e| {
+            let entry = e.entry(name.to_string()).or_default();
+
+            entry.retain(|err| err.code != MIN_ITEMS && err.code != MAX_ITEMS);
+            entry.extend(f_errors);
+
+            if entry.is_empty() {
+                e.remove(name);
+            }
+        });
+    }
+
+    fn restore(&self, values: T, options: ResetOptions) {
+        if self
+            .queued_submit
+            .try_update_value(Option::take)
+            .flatten()
+            .is_some()
+        {
+            self.submitting.set(false);
+        }
+
+        self.regenerate_keys(&values, |_| true);
+        self.values.set(values);
+        self.files.update(|files| files.clear());
+        self.cancel_async_validation(|_| true);
+
+        if !options.keep_errors {
+            self.clear_errors();
+        }
+
+        if !options.keep_touched {
+            self.touched.update(|touched| touched.clear());
+            self.edited.update(|edited| edited.clear());
+        }
+
+        if !options.keep_submit_count {
+            self.submit_count.set(0);
+            self.group.set_value(None);
+            self.submit_successful.set(false);
+            self.submit_error.set(None);
+        }
+    }
+
+    /// Replaces the row keys of the list fields for which `matches` returns
+    /// `true` with new keys, one for each row the list holds in `values`
+    fn regenerate_keys(&self, values: &T, matches: impl Fn(&str) -> bool) {
+        let next_key = self.next_key;
+
+        self.array_keys.update(|array_keys| {
+            next_key.update_value(|next| {
+                for (name, keys) in array_keys.iter_mut() {
+                    if !matches(name) {
+                        continue;
+                    }
+
+                    *keys = (0..list_len(values, name))
+                        .map(|_| {
+                            *next += 1;
+                            *next
+                        })
+                        .collect();
+                }
+            });
+        });
+    }
+
+    /// Compares the value of a field with its initial value, tracking both
+    fn field_is_dirty(&self, field: &str) -> bool {
+        let has_files = self
+            .files
+            .with(|files| files.get(field).is_some_and(|files| !files.is_empty()));
+
+        has_files
+            || self.values.with(|values| {
+                self.initial.with(|initial| {
+                    match (values.get_value(field), initial.get_value(field)) {
+                        (None, None) => {
+                            values.list(field).map(FieldList::len)
+                                != initial.list(field).map(FieldList::len)
+                        }
+                        (value, initial) => value != initial,
+                    }
+                })
+            })
+    }
+
+    /// Records that the value of a field was written through the form, so
+    /// it is considered by [`Form::dirty_fields`]
+    fn mark_edited(&self, field: &str) {
+        if !self.edited.with_untracked(|edited| edited.contains(field)) {
+            self.edited.update(|edited| {
+                edited.insert(field.to_string());
+            });
+        }
+    }
+
+    /// Stores the outcome of writing a field value: a parse error replaces
+    /// the field errors, while a successful write clears them.
+    fn record_parse_result(&self, field: &str, result: Option<Result<(), FieldParseError>>) {
+        match result {
+            Some(Ok(())) => {
+                self.mark_edited(field);
+                self.clear_error(field);
             }
+            Some(Err(err)) => {
+                let err = FieldError::from(err);
 
-            cb(values.get());
+                self.parse_errors.update(|e| {
+                    e.insert(field.to_string(), err.clone());
+                });
+                self.errors.update(|e| {
+                    e.insert(field.to_string(), FieldErrors::from(vec![err]));
+                });
+            }
+            None => {}
         }
     }
 }
+
+impl<T: Clone + Default + FormStruct + Send + Sync + 'static, V: FormValidator<T> + Default> Default
+    for Form<T, V>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+    struct SignUp {
+        email: String,
+        name: String,
+    }
+
+    fn form() -> Form<SignUp, impl FormValidator<SignUp>> {
+        Form::with_validator(|_: &SignUp| Ok::<_, FormErrors>(()))
+    }
+
+    #[test]
+    fn server_field_errors_are_cleared_by_editing_the_field() {
+        let form = form();
+
+        form.set_server_errors(
+            FormErrors::new()
+                .with_field_error("email", FieldError::new("taken"))
+                .with_field_error("name", FieldError::new("reserved"))
+                .with_form_error(FieldError::new("rate_limited").with_message("try again later")),
+        );
+
+        assert_eq!(
+            form.error("email").get_untracked().as_deref(),
+            Some("is invalid")
+        );
+
+        form.set_field_value("email", Some("ada@example.com".into()));
+
+        assert_eq!(form.error("email").get_untracked(), None);
+        assert!(form.error("name").get_untracked().is_some());
+        assert_eq!(
+            form.form_error_messages().get_untracked(),
+            ["try again later"]
+        );
+        assert!(!form.state().is_valid.get_untracked());
+    }
+
+    #[test]
+    fn server_form_errors_are_cleared_by_the_next_submission() {
+        let form = form();
+        let submitted = Arc::new(std::sync::Mutex::new(None));
+
+        form.set_server_errors(FormErrors::new().with_form_error(FieldError::new("rate_limited")));
+        form.trigger(&["email", "name"]);
+        assert_eq!(form.form_error_messages().get_untracked().len(), 1);
+
+        let out = submitted.clone();
+        form.submit(None, move |values| {
+            *out.lock().unwrap() = Some(values);
+            true
+        });
+
+        assert!(form.errors().get_untracked().is_empty());
+        assert_eq!(*submitted.lock().unwrap(), Some(SignUp::default()));
+        assert!(form.state().is_submit_successful.get_untracked());
+    }
+
+    /// Pending checks of an [`AsyncValidator`], resolved by the test
+    type Checks = Arc<std::sync::Mutex<Vec<futures::channel::oneshot::Sender<Option<FieldError>>>>>;
+
+    /// Builds a form whose email is checked by an [`AsyncValidator`] that
+    /// waits for the test to resolve each check
+    fn form_with_checks() -> (Form<SignUp, impl FormValidator<SignUp>>, Checks) {
+        let _ = leptos::task::Executor::init_futures_executor();
+
+        let checks = Checks::default();
+        let pending = checks.clone();
+        let validator = AsyncValidator::new(move |_| {
+            let (tx, rx) = futures::channel::oneshot::channel();
+            pending.lock().unwrap().push(tx);
+
+            async move { rx.await.ok().flatten().map_or(Ok(()), Err) }
+        });
+
+        (form().with_async_validator("email", validator), checks)
+    }
+
+    fn resolve(checks: &Checks, idx: usize, error: Option<FieldError>) {
+        let tx = std::mem::replace(
+            &mut checks.lock().unwrap()[idx],
+            futures::channel::oneshot::channel().0,
+        );
+
+        let _ = tx.send(error);
+        leptos::task::Executor::poll_local();
+    }
+
+    #[test]
+    fn submission_waits_for_unchecked_values() {
+        let (form, checks) = form_with_checks();
+        let submitted = Rc::new(std::cell::Cell::new(0));
+        let state = form.state();
+
+        form.set_field_value("email", Some("ada@example.com".into()));
+
+        let count = submitted.clone();
+        form.submit(None, move |_| {
+            count.set(count.get() + 1);
+            true
+        });
+
+        assert_eq!(submitted.get(), 0);
+        assert!(state.is_submitting.get_untracked());
+        assert!(state.is_validating.get_untracked());
+
+        let count = submitted.clone();
+        form.submit(None, move |_| {
+            count.set(count.get() + 1);
+            true
+        });
+        assert_eq!(state.submit_count.get_untracked(), 1);
+
+        resolve(&checks, 0, None);
+
+        assert_eq!(submitted.get(), 1);
+        assert_eq!(checks.lock().unwrap().len(), 1);
+        assert!(!state.is_submitting.get_untracked());
+        assert!(state.is_submit_successful.get_untracked());
+    }
+
+    #[test]
+    fn submission_is_dropped_when_a_check_fails() {
+        let (form, checks) = form_with_checks();
+        let submitted = Rc::new(std::cell::Cell::new(false));
+        let state = form.state();
+
+        let out = submitted.clone();
+        form.submit(None, move |_| {
+            out.set(true);
+            true
+        });
+        resolve(&checks, 0, Some(FieldError::new("taken")));
+
+        assert!(!submitted.get());
+        assert!(!state.is_submitting.get_untracked());
+        assert!(!state.is_submit_successful.get_untracked());
+        assert_eq!(
+            form.errors_for("email").get_untracked(),
+            [FieldError::new("taken")]
+        );
+    }
+
+    #[test]
+    fn submission_waits_for_the_latest_value() {
+        let (form, checks) = form_with_checks();
+        let submitted = Rc::new(std::cell::RefCell::new(None));
+
+        let out = submitted.clone();
+        form.submit(None, move |values| {
+            *out.borrow_mut() = Some(values);
+            true
+        });
+
+        form.set_field_value("email", Some("ada@example.com".into()));
+        leptos::task::Executor::poll_local();
+        assert_eq!(checks.lock().unwrap().len(), 2);
+
+        resolve(&checks, 0, Some(FieldError::new("taken")));
+        assert!(submitted.borrow().is_none());
+        assert!(form.errors_for("email").get_untracked().is_empty());
+
+        resolve(&checks, 1, None);
+        assert_eq!(
+            submitted
+                .borrow()
+                .as_ref()
+                .map(|values| values.email.as_str()),
+            Some("ada@example.com")
+        );
+    }
+
+    #[test]
+    fn results_of_replaced_runs_are_ignored() {
+        let (form, checks) = form_with_checks();
+        let validating = form.is_validating("email");
+
+        form.set_field_value("email", Some("ada@example.com".into()));
+        form.validate_field("email");
+        form.invalidate_async_cache("email");
+        form.validate_field("email");
+        assert_eq!(checks.lock().unwrap().len(), 2);
+
+        resolve(&checks, 0, Some(FieldError::new("taken")));
+        assert!(validating.get_untracked());
+        assert!(form.errors_for("email").get_untracked().is_empty());
+
+        resolve(&checks, 1, Some(FieldError::new("banned")));
+        assert!(!validating.get_untracked());
+        assert_eq!(
+            form.errors_for("email").get_untracked(),
+            [FieldError::new("banned")]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::FieldError;
+
+const FALLBACK_TEMPLATE: &str = "is invalid";
+
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("length.equal", "must be exactly {equal} characters"),
+    ("length.min", "must be at least {min} characters"),
+    ("length.max", "must be at most {max} characters"),
+    (
+        "length.min_max",
+        "must be between {min} and {max} characters",
+    ),
+    ("range.min", "must be at least {min}"),
+    ("range.max", "must be at most {max}"),
+    ("range.min_max", "must be between {min} and {max}"),
+    (
+        "range.exclusive_min",
+        "must be greater than {exclusive_min}",
+    ),
+    ("range.exclusive_max", "must be less than {exclusive_max}"),
+    (
+        "range.exclusive_min_max",
+        "must be greater than {exclusive_min} and less than {exclusive_max}",
+    ),
+    ("email", "must be a valid email address"),
+    ("url", "must be a valid URL"),
+    ("required", "is required"),
+    ("must_match", "must match {other}"),
+    ("contains", "must contain {needle}"),
+    ("does_not_contain", "must not contain {needle}"),
+    ("regex", "has an invalid format"),
+    ("credit_card", "must be a valid credit card number"),
+    (
+        "non_control_character",
+        "must not contain control characters",
+    ),
+    ("min_items", "must have at least {min} items"),
+    ("max_items", "must have at most {max} items"),
+    ("min_files", "must have at least {min} files"),
+    ("max_files", "must have at most {max} files"),
+    ("file_size", "{name} must be at most {max} bytes"),
+    ("file_type", "{name} must be one of: {accept}"),
+    ("bool", "must be true or false"),
+    ("integer", "must be a whole number"),
+    ("number", "must be a number"),
+];
+
+/// Builds display text for a [`FieldError`].
+///
+/// Errors carrying a custom message use it as is. Otherwise the message is
+/// built from the template registered for the error `code`, replacing
+/// `{param}` placeholders with the error params.
+///
+/// Rules with bounds, such as `length` and `range`, first look up a template
+/// named after the bounds present in the params, e.g. `length.min` or
+/// `range.min_max`, before falling back to the plain `code`.
+#[derive(Clone, Debug)]
+pub struct MessageResolver {
+    templates: HashMap<Cow<'static, str>, Cow<'static, str>>,
+}
+
+impl MessageResolver {
+    /// Creates a resolver with the built-in templates
+    pub fn new() -> Self {
+        let templates = DEFAULT_TEMPLATES
+            .iter()
+            .map(|(code, template)| (Cow::Borrowed(*code), Cow::Borrowed(*template)))
+            .collect();
+
+        Self { templates }
+    }
+
+    /// Creates a resolver without any template
+    pub fn empty() -> Self {
+        Self {
+            templates: HashMap::new(),
+        }
+    }
+
+    /// Registers the template used for errors with the provided `code`,
+    /// replacing the existing one
+    pub fn with_template(
+        mut self,
+        code: impl Into<Cow<'static, str>>,
+        template: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.set_template(code, template);
+        self
+    }
+
+    pub fn set_template(
+        &mut self,
+        code: impl Into<Cow<'static, str>>,
+        template: impl Into<Cow<'static, str>>,
+    ) {
+        self.templates.insert(code.into(), template.into());
+    }
+
+    /// Retrieves the template registered for the error, if any
+    pub fn template(&self, err: &FieldError) -> Option<&str> {
+        if let Some(variant) = bounds_variant(err) {
+            let key = format!("{}.{variant}", err.code);
+
+            if let Some(template) = self.templates.get(key.as_str()) {
+                return Some(template);
+            }
+        }
+
+        self.templates.get(err.code.as_ref()).map(AsRef::as_ref)
+    }
+
+    /// Builds the display text for the error
+    pub fn resolve(&self, err: &FieldError) -> String {
+        let template = match &err.message {
+            Some(message) if !message.is_empty() => message.as_ref(),
+            _ => self.template(err).unwrap_or(FALLBACK_TEMPLATE),
+        };
+
+        interpolate(template, &err.params)
+    }
+}
+
+impl Default for MessageResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Names the bounds present in the params of a `length` or `range` error
+fn bounds_variant(err: &FieldError) -> Option<&'static str> {
+    let has = |name: &str| err.params.contains_key(name);
+
+    if has("equal") {
+        return Some("equal");
+    }
+
+    match (
+        has("min"),
+        has("max"),
+        has("exclusive_min"),
+        has("exclusive_max"),
+    ) {
+        (true, true, _, _) => Some("min_max"),
+        (true, false, _, _) => Some("min"),
+        (false, true, _, _) => Some("max"),
+        (_, _, true, true) => Some("exclusive_min_max"),
+        (_, _, true, false) => Some("exclusive_min"),
+        (_, _, false, true) => Some("exclusive_max"),
+        _ => None,
+    }
+}
+
+/// Replaces `{param}` placeholders in the template with the param values
+pub(crate) fn interpolate(template: &str, params: &HashMap<Cow<'static, str>, Value>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+
+        let Some(end) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let name = &rest[start + 1..start + end];
+
+        match params.get(name) {
+            Some(Value::String(value)) => out.push_str(value),
+            Some(value) => out.push_str(&value.to_string()),
+            None => out.push_str(&rest[start..=start + end]),
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_variant_follows_params() {
+        let err = |params: &[&str]| {
+            params.iter().fold(FieldError::new("range"), |err, name| {
+                err.with_param(name.to_string(), 1)
+            })
+        };
+
+        assert_eq!(bounds_variant(&err(&["equal", "min"])), Some("equal"));
+        assert_eq!(bounds_variant(&err(&["min", "max"])), Some("min_max"));
+        assert_eq!(bounds_variant(&err(&["min"])), Some("min"));
+        assert_eq!(bounds_variant(&err(&["max", "exclusive_min"])), Some("max"));
+        assert_eq!(
+            bounds_variant(&err(&["exclusive_min", "exclusive_max"])),
+            Some("exclusive_min_max")
+        );
+        assert_eq!(
+            bounds_variant(&err(&["exclusive_max"])),
+            Some("exclusive_max")
+        );
+        assert_eq!(bounds_variant(&err(&["value"])), None);
+    }
+
+    #[test]
+    fn interpolate_replaces_known_params() {
+        let params = FieldError::new("length")
+            .with_param("min", 3)
+            .with_param("name", "title")
+            .params;
+
+        assert_eq!(
+            interpolate("{name} needs {min} characters", &params),
+            "title needs 3 characters"
+        );
+        assert_eq!(interpolate("{max} or {min}", &params), "{max} or 3");
+        assert_eq!(interpolate("unclosed {min", &params), "unclosed {min");
+        assert_eq!(interpolate("no params", &params), "no params");
+    }
+}
@@ -0,0 +1,33 @@
+/// Determines which user interactions trigger field validation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Validates fields only when the form is submitted
+    #[default]
+    OnSubmit,
+    /// Validates a field every time its value changes
+    OnChange,
+    /// Validates a field when it loses focus
+    OnBlur,
+    /// Validates a field when it loses focus for the first time, and on every
+    /// change afterwards
+    OnTouched,
+    /// Validates a field on both change and blur
+    All,
+}
+
+impl ValidationMode {
+    pub(crate) fn validates_on_change(self, touched: bool) -> bool {
+        match self {
+            ValidationMode::OnChange | ValidationMode::All => true,
+            ValidationMode::OnTouched => touched,
+            ValidationMode::OnSubmit | ValidationMode::OnBlur => false,
+        }
+    }
+
+    pub(crate) fn validates_on_blur(self) -> bool {
+        matches!(
+            self,
+            ValidationMode::OnBlur | ValidationMode::OnTouched | ValidationMode::All
+        )
+    }
+}
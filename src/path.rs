@@ -0,0 +1,115 @@
+use crate::{FieldList, FieldParseError, FieldValue, FormStruct};
+
+/// Access to the fields of a nested form value through the remainder of a
+/// field path.
+///
+/// The remainder starts right after the name of the field holding the nested
+/// value, e.g. `.city` for `shipping.city` or `[2].qty` for `items[2].qty`.
+///
+/// Implemented for every [`FormStruct`] and for lists of them, which lets
+/// `#[form(nested)]` fields delegate path access when deriving `FormStruct`.
+pub trait NestedFields {
+    fn get_nested(&self, rest: &str) -> Option<FieldValue>;
+    fn set_nested(&mut self, rest: &str, value: FieldValue) -> Result<(), FieldParseError>;
+    fn list_nested(&self, rest: &str) -> Option<&dyn FieldList>;
+    fn list_nested_mut(&mut self, rest: &str) -> Option<&mut dyn FieldList>;
+
+    /// Lists the paths of every field of the nested value, prefixed with the
+    /// path of the field holding it
+    fn nested_field_names(&self, prefix: &str) -> Vec<String>;
+
+    /// Exposes the nested value itself as a list, if it is one
+    fn as_list(&self) -> Option<&dyn FieldList> {
+        None
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn FieldList> {
+        None
+    }
+}
+
+impl<T: FormStruct> NestedFields for T {
+    fn get_nested(&self, rest: &str) -> Option<FieldValue> {
+        self.get_value(rest.strip_prefix('.')?)
+    }
+
+    fn set_nested(&mut self, rest: &str, value: FieldValue) -> Result<(), FieldParseError> {
+        match rest.strip_prefix('.') {
+            Some(name) => self.set_value(name, value),
+            None => Ok(()),
+        }
+    }
+
+    fn list_nested(&self, rest: &str) -> Option<&dyn FieldList> {
+        self.list(rest.strip_prefix('.')?)
+    }
+
+    fn list_nested_mut(&mut self, rest: &str) -> Option<&mut dyn FieldList> {
+        self.list_mut(rest.strip_prefix('.')?)
+    }
+
+    fn nested_field_names(&self, prefix: &str) -> Vec<String> {
+        self.field_names()
+            .into_iter()
+            .map(|name| format!("{prefix}.{name}"))
+            .collect()
+    }
+}
+
+impl<T: NestedFields + Clone + 'static> NestedFields for Vec<T> {
+    fn get_nested(&self, rest: &str) -> Option<FieldValue> {
+        let (idx, rest) = split_index(rest)?;
+
+        self.get(idx)?.get_nested(rest)
+    }
+
+    fn set_nested(&mut self, rest: &str, value: FieldValue) -> Result<(), FieldParseError> {
+        match split_index(rest).and_then(|(idx, rest)| Some((self.get_mut(idx)?, rest))) {
+            Some((item, rest)) => item.set_nested(rest, value),
+            None => Ok(()),
+        }
+    }
+
+    fn list_nested(&self, rest: &str) -> Option<&dyn FieldList> {
+        let (idx, rest) = split_index(rest)?;
+
+        self.get(idx)?.list_nested(rest)
+    }
+
+    fn list_nested_mut(&mut self, rest: &str) -> Option<&mut dyn FieldList> {
+        let (idx, rest) = split_index(rest)?;
+
+        self.get_mut(idx)?.list_nested_mut(rest)
+    }
+
+    fn nested_field_names(&self, prefix: &str) -> Vec<String> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(idx, item)| item.nested_field_names(&format!("{prefix}[{idx}]")))
+            .collect()
+    }
+
+    fn as_list(&self) -> Option<&dyn FieldList> {
+        Some(self)
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn FieldList> {
+        Some(self)
+    }
+}
+
+/// Splits a leading `[idx]` segment off a field path
+pub(crate) fn split_index(rest: &str) -> Option<(usize, &str)> {
+    let rest = rest.strip_prefix('[')?;
+    let end = rest.find(']')?;
+    let idx = rest[..end].parse().ok()?;
+
+    Some((idx, &rest[end + 1..]))
+}
+
+/// Whether `path` is `field` itself or the path of a value nested in it,
+/// such as `items[2].qty` in `items`
+pub(crate) fn is_within(path: &str, field: &str) -> bool {
+    path.strip_prefix(field)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+}
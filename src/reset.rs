@@ -0,0 +1,72 @@
+use crate::FormStruct;
+use crate::path::is_within;
+
+/// Determines which parts of the form state survive a reset.
+///
+/// By default, a reset clears errors, touched and dirty state and the submit
+/// count.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ResetOptions {
+    pub(crate) keep_errors: bool,
+    pub(crate) keep_touched: bool,
+    pub(crate) keep_submit_count: bool,
+    pub(crate) keep_initial_values: bool,
+}
+
+impl ResetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the field errors
+    pub fn keep_errors(mut self) -> Self {
+        self.keep_errors = true;
+        self
+    }
+
+    /// Keeps which fields were touched and edited
+    pub fn keep_touched(mut self) -> Self {
+        self.keep_touched = true;
+        self
+    }
+
+    /// Keeps the submit count, and thus whether the form was submitted
+    pub fn keep_submit_count(mut self) -> Self {
+        self.keep_submit_count = true;
+        self
+    }
+
+    /// Keeps the initial values used to compute dirty state when resetting
+    /// to new values, so fields differing from them remain dirty
+    pub fn keep_initial_values(mut self) -> Self {
+        self.keep_initial_values = true;
+        self
+    }
+}
+
+/// Writes the value `field` holds in `initial` into `values`.
+///
+/// Lists are replaced as a whole, while nested values are restored field by
+/// field.
+pub(crate) fn restore_field<T: FormStruct>(values: &mut T, initial: &T, field: &str) {
+    if let Some(value) = initial.get_value(field) {
+        let _ = values.set_value(field, value);
+        return;
+    }
+
+    if let Some(rows) = initial.list(field) {
+        if let Some(list) = values.list_mut(field) {
+            list.assign(rows);
+        }
+
+        return;
+    }
+
+    for name in initial.field_names() {
+        if is_within(&name, field)
+            && let Some(value) = initial.get_value(&name)
+        {
+            let _ = values.set_value(&name, value);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use leptos::prelude::Signal;
+
+/// Reactive status of a form as a whole, retrieved using
+/// [`Form::state`](crate::Form::state)
+#[derive(Clone, Copy)]
+pub struct FormState {
+    /// Whether the values pass validation and no field has errors
+    pub is_valid: Signal<bool>,
+    /// Whether any field is being validated asynchronously
+    pub is_validating: Signal<bool>,
+    /// Whether a submission is waiting for asynchronous validation to
+    /// settle, or its submit handler is running
+    pub is_submitting: Signal<bool>,
+    /// Whether the form was submitted at least once
+    pub is_submitted: Signal<bool>,
+    /// Whether the last submission passed validation and its handler
+    /// completed
+    pub is_submit_successful: Signal<bool>,
+    /// Number of times the form was submitted
+    pub submit_count: Signal<usize>,
+    /// Error returned by the last asynchronous submission, if it failed
+    pub submit_error: Signal<Option<String>>,
+    /// Whether the value of any field differs from its initial value
+    pub is_dirty: Signal<bool>,
+}
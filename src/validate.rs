@@ -0,0 +1,55 @@
+#[cfg(feature = "garde")]
+mod garde;
+
+use crate::FormErrors;
+
+#[cfg(feature = "garde")]
+pub use garde::GardeAdapter;
+
+/// Validation backend of a [`Form`](crate::Form), checking the form values
+/// as a whole.
+///
+/// Adapters are provided for [`validator`](https://docs.rs/validator) and,
+/// behind the `garde` feature, [`garde`](https://docs.rs/garde). Closures of
+/// the form `Fn(&T) -> Result<(), FormErrors>` implement this trait as well,
+/// which covers hand-written checks.
+///
+/// Validators do not depend on the browser, so they can run during SSR and
+/// within server functions, reporting the same [`FormErrors`] a `Form` does.
+pub trait FormValidator<T>: Send + Sync + 'static {
+    fn validate(&self, values: &T) -> Result<(), FormErrors>;
+}
+
+impl<T, F> FormValidator<T> for F
+where
+    F: Fn(&T) -> Result<(), FormErrors> + Send + Sync + 'static,
+{
+    fn validate(&self, values: &T) -> Result<(), FormErrors> {
+        self(values)
+    }
+}
+
+/// [`FormValidator`] for values implementing `validator::Validate`, used by
+/// default.
+///
+/// Requires the `validator` feature, enabled by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValidatorAdapter;
+
+#[cfg(feature = "validator")]
+impl<T: validator::Validate> FormValidator<T> for ValidatorAdapter {
+    fn validate(&self, values: &T) -> Result<(), FormErrors> {
+        values.validate().map_err(|err| FormErrors::from(&err))
+    }
+}
+
+/// Validates form values outside of a [`Form`](crate::Form), such as within
+/// a server function, reporting errors the same way a `Form` does on submit.
+///
+/// Does not depend on the browser, so it is safe to call during SSR. The
+/// returned [`FormErrors`] can be sent back to the client and applied using
+/// [`Form::set_server_errors`](crate::Form::set_server_errors).
+#[cfg(feature = "validator")]
+pub fn validate<T: validator::Validate>(values: &T) -> Result<(), FormErrors> {
+    ValidatorAdapter.validate(values)
+}
@@ -0,0 +1,38 @@
+use crate::{FieldError, FormErrors, FormValidator};
+
+/// Code of the errors reported by [`GardeAdapter`], which carry the message
+/// built by `garde`
+const GARDE: &str = "garde";
+
+/// [`FormValidator`] for values implementing `garde::Validate`.
+///
+/// Errors are reported under their `garde` path, such as `items[2].qty`,
+/// while errors of the value itself become form-level errors.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GardeAdapter;
+
+impl<T> FormValidator<T> for GardeAdapter
+where
+    T: garde::Validate,
+    T::Context: Default,
+{
+    fn validate(&self, values: &T) -> Result<(), FormErrors> {
+        let Err(report) = values.validate() else {
+            return Ok(());
+        };
+
+        let mut errors = FormErrors::new();
+
+        for (path, err) in report.iter() {
+            let error = FieldError::new(GARDE).with_message(err.message().to_string());
+
+            if path.is_empty() {
+                errors.push_form_error(error);
+            } else {
+                errors.push_field_error(path.to_string(), error);
+            }
+        }
+
+        Err(errors)
+    }
+}
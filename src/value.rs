@@ -0,0 +1,335 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+
+/// Typed value of a form field
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    List(Vec<FieldValue>),
+    #[default]
+    Null,
+}
+
+impl FieldValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, FieldValue::Null)
+    }
+
+    /// Whether the value holds no user input, such as [`FieldValue::Null`]
+    /// or blank text.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FieldValue::Null => true,
+            FieldValue::Text(text) => text.trim().is_empty(),
+            FieldValue::List(items) => items.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Text(text) => f.write_str(text),
+            FieldValue::Bool(value) => write!(f, "{value}"),
+            FieldValue::Integer(value) => write!(f, "{value}"),
+            FieldValue::Float(value) => write!(f, "{value}"),
+            FieldValue::List(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{item}")?;
+                }
+
+                Ok(())
+            }
+            FieldValue::Null => Ok(()),
+        }
+    }
+}
+
+/// Error produced when a [`FieldValue`] cannot be converted into the type
+/// of the field it is written to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldParseError {
+    code: Cow<'static, str>,
+    message: Cow<'static, str>,
+}
+
+impl FieldParseError {
+    pub fn new(code: impl Into<Cow<'static, str>>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for FieldParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FieldParseError {}
+
+/// Conversion from a [`FieldValue`] into the type of a form field
+pub trait FromFieldValue: Sized {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError>;
+}
+
+/// Conversion from the type of a form field into a [`FieldValue`]
+pub trait IntoFieldValue {
+    fn to_field_value(&self) -> FieldValue;
+}
+
+impl FromFieldValue for FieldValue {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+        Ok(value)
+    }
+}
+
+impl IntoFieldValue for FieldValue {
+    fn to_field_value(&self) -> FieldValue {
+        self.clone()
+    }
+}
+
+impl FromFieldValue for String {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+        Ok(value.to_string())
+    }
+}
+
+impl IntoFieldValue for String {
+    fn to_field_value(&self) -> FieldValue {
+        FieldValue::Text(self.clone())
+    }
+}
+
+impl IntoFieldValue for str {
+    fn to_field_value(&self) -> FieldValue {
+        FieldValue::Text(self.to_string())
+    }
+}
+
+impl FromFieldValue for bool {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+        match value {
+            FieldValue::Bool(value) => Ok(value),
+            FieldValue::Null => Ok(false),
+            FieldValue::Integer(value) => Ok(value != 0),
+            FieldValue::Text(text) => match text.trim().to_lowercase().as_str() {
+                "true" | "on" | "yes" | "1" => Ok(true),
+                "false" | "off" | "no" | "0" | "" => Ok(false),
+                _ => Err(FieldParseError::new("bool", "must be true or false")),
+            },
+            _ => Err(FieldParseError::new("bool", "must be true or false")),
+        }
+    }
+}
+
+impl IntoFieldValue for bool {
+    fn to_field_value(&self) -> FieldValue {
+        FieldValue::Bool(*self)
+    }
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromFieldValue for $ty {
+                fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+                    let err = || FieldParseError::new("integer", "must be a whole number");
+
+                    match value {
+                        FieldValue::Integer(value) => <$ty>::try_from(value).map_err(|_| err()),
+                        FieldValue::Float(value) if value.fract() == 0.0 => {
+                            <$ty>::try_from(value as i64).map_err(|_| err())
+                        }
+                        FieldValue::Text(text) => text.trim().parse::<$ty>().map_err(|_| err()),
+                        _ => Err(err()),
+                    }
+                }
+            }
+
+            impl IntoFieldValue for $ty {
+                fn to_field_value(&self) -> FieldValue {
+                    match i64::try_from(*self) {
+                        Ok(value) => FieldValue::Integer(value),
+                        Err(_) => FieldValue::Text(self.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl FromFieldValue for $ty {
+                fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+                    let err = || FieldParseError::new("number", "must be a number");
+
+                    match value {
+                        FieldValue::Float(value) => Ok(value as $ty),
+                        FieldValue::Integer(value) => Ok(value as $ty),
+                        FieldValue::Text(text) => text.trim().parse::<$ty>().map_err(|_| err()),
+                        _ => Err(err()),
+                    }
+                }
+            }
+
+            impl IntoFieldValue for $ty {
+                fn to_field_value(&self) -> FieldValue {
+                    FieldValue::Float(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl<T: FromFieldValue> FromFieldValue for Option<T> {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        T::from_field_value(value).map(Some)
+    }
+}
+
+impl<T: IntoFieldValue> IntoFieldValue for Option<T> {
+    fn to_field_value(&self) -> FieldValue {
+        match self {
+            Some(value) => value.to_field_value(),
+            None => FieldValue::Null,
+        }
+    }
+}
+
+impl<T: FromFieldValue> FromFieldValue for Vec<T> {
+    fn from_field_value(value: FieldValue) -> Result<Self, FieldParseError> {
+        match value {
+            FieldValue::List(items) => items.into_iter().map(T::from_field_value).collect(),
+            FieldValue::Null => Ok(Vec::new()),
+            FieldValue::Text(text) if text.trim().is_empty() => Ok(Vec::new()),
+            FieldValue::Text(text) => text
+                .split(',')
+                .map(|item| T::from_field_value(FieldValue::Text(item.trim().to_string())))
+                .collect(),
+            value => T::from_field_value(value).map(|item| vec![item]),
+        }
+    }
+}
+
+impl<T: IntoFieldValue> IntoFieldValue for Vec<T> {
+    fn to_field_value(&self) -> FieldValue {
+        FieldValue::List(self.iter().map(IntoFieldValue::to_field_value).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> FieldValue {
+        FieldValue::Text(value.to_string())
+    }
+
+    #[test]
+    fn bool_accepts_checkbox_values() {
+        assert_eq!(bool::from_field_value(text(" On ")), Ok(true));
+        assert_eq!(bool::from_field_value(text("")), Ok(false));
+        assert_eq!(bool::from_field_value(FieldValue::Null), Ok(false));
+        assert_eq!(bool::from_field_value(FieldValue::Integer(2)), Ok(true));
+        assert_eq!(
+            bool::from_field_value(text("maybe")).unwrap_err().code(),
+            "bool"
+        );
+    }
+
+    #[test]
+    fn integers_are_range_checked() {
+        assert_eq!(u8::from_field_value(text(" 42 ")), Ok(42));
+        assert_eq!(u8::from_field_value(FieldValue::Float(3.0)), Ok(3));
+        assert_eq!(
+            u8::from_field_value(FieldValue::Integer(256))
+                .unwrap_err()
+                .code(),
+            "integer"
+        );
+        assert!(u8::from_field_value(FieldValue::Float(1.5)).is_err());
+        assert!(i32::from_field_value(text("1e3")).is_err());
+        assert_eq!(u64::MAX.to_field_value(), text("18446744073709551615"));
+    }
+
+    #[test]
+    fn floats_accept_integers() {
+        assert_eq!(f64::from_field_value(FieldValue::Integer(2)), Ok(2.0));
+        assert_eq!(f32::from_field_value(text("0.5")), Ok(0.5));
+        assert_eq!(
+            f64::from_field_value(FieldValue::Bool(true))
+                .unwrap_err()
+                .code(),
+            "number"
+        );
+    }
+
+    #[test]
+    fn empty_value_is_none() {
+        assert_eq!(Option::<u32>::from_field_value(text("  ")), Ok(None));
+        assert_eq!(Option::<u32>::from_field_value(FieldValue::Null), Ok(None));
+        assert_eq!(Option::<u32>::from_field_value(text("7")), Ok(Some(7)));
+        assert_eq!(None::<u32>.to_field_value(), FieldValue::Null);
+    }
+
+    #[test]
+    fn text_is_split_into_lists() {
+        assert_eq!(
+            Vec::<u32>::from_field_value(text("1, 2,3")),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(Vec::<u32>::from_field_value(text("")), Ok(Vec::new()));
+        assert_eq!(
+            Vec::<u32>::from_field_value(FieldValue::Null),
+            Ok(Vec::new())
+        );
+        assert_eq!(
+            Vec::<u32>::from_field_value(FieldValue::Integer(5)),
+            Ok(vec![5])
+        );
+        assert!(Vec::<u32>::from_field_value(text("1,x")).is_err());
+        assert_eq!(
+            vec![true, false].to_field_value(),
+            FieldValue::List(vec![FieldValue::Bool(true), FieldValue::Bool(false)])
+        );
+    }
+
+    #[test]
+    fn lists_are_displayed_comma_separated() {
+        let value = FieldValue::List(vec![text("a"), FieldValue::Integer(1), FieldValue::Null]);
+
+        assert_eq!(value.to_string(), "a,1,");
+        assert!(FieldValue::List(Vec::new()).is_empty());
+        assert!(!FieldValue::Bool(false).is_empty());
+    }
+}
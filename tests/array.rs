@@ -0,0 +1,117 @@
+use leptos::prelude::GetUntracked;
+use urlap::{Form, FormErrors, FormStruct, FormValidator, ItemCount};
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Line {
+    sku: String,
+    qty: u32,
+}
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Order {
+    #[form(nested)]
+    items: Vec<Line>,
+}
+
+fn form(rows: usize) -> Form<Order, impl FormValidator<Order>> {
+    let order = Order {
+        items: (0..rows)
+            .map(|idx| Line {
+                sku: format!("SKU-{idx}"),
+                qty: 1,
+            })
+            .collect(),
+    };
+
+    Form::with_validator_and_values(|_: &Order| Ok::<_, FormErrors>(()), order)
+}
+
+fn error_fields(form: &Form<Order, impl FormValidator<Order>>) -> Vec<String> {
+    let mut fields: Vec<String> = form.errors().get_untracked().fields.into_keys().collect();
+
+    fields.sort();
+    fields
+}
+
+#[test]
+fn errors_follow_their_row() {
+    let form = form(3);
+    let items = form.field_array::<Line>("items");
+
+    form.set_error("items[0].sku", "taken");
+    form.set_error("items[2].qty", "too many");
+
+    items.swap(0, 1);
+    assert_eq!(error_fields(&form), ["items[1].sku", "items[2].qty"]);
+
+    items.move_item(2, 0);
+    assert_eq!(error_fields(&form), ["items[0].qty", "items[2].sku"]);
+
+    items.remove(0);
+    assert_eq!(error_fields(&form), ["items[1].sku"]);
+    assert_eq!(items.len().get_untracked(), 2);
+}
+
+#[test]
+fn out_of_range_rows_are_left_alone() {
+    let form = form(3);
+    let items = form.field_array::<Line>("items");
+    let keys = items.fields().get_untracked();
+
+    form.set_error("items[0].sku", "taken");
+
+    items.swap(0, 7);
+    items.move_item(0, 9);
+    items.move_item(9, 0);
+    items.remove(3);
+
+    assert_eq!(error_fields(&form), ["items[0].sku"]);
+    assert_eq!(items.fields().get_untracked(), keys);
+    assert!(form.dirty_fields().get_untracked().is_empty());
+}
+
+#[test]
+fn item_count_errors_update_as_rows_change() {
+    let form = form(2).with_item_count(
+        "items",
+        ItemCount::new()
+            .min(1)
+            .max(2)
+            .max_message("at most two lines per order"),
+    );
+    let items = form.field_array::<Line>("items");
+    let error = form.error("items");
+
+    items.append(Line::default());
+    assert_eq!(
+        error.get_untracked().as_deref(),
+        Some("at most two lines per order")
+    );
+
+    items.remove(0);
+    assert_eq!(error.get_untracked(), None);
+
+    items.remove(0);
+    items.remove(0);
+    assert_eq!(
+        error.get_untracked().as_deref(),
+        Some("must have at least 1 items")
+    );
+
+    items.insert(0, Line::default());
+    assert_eq!(error.get_untracked(), None);
+}
+
+#[test]
+fn item_count_keeps_other_errors_of_the_list() {
+    let form = form(0).with_item_count("items", ItemCount::new().min(1));
+    let items = form.field_array::<Line>("items");
+
+    form.set_error("items", "pick a warehouse first");
+    items.append(Line::default());
+
+    assert_eq!(
+        form.error("items").get_untracked().as_deref(),
+        Some("pick a warehouse first")
+    );
+}
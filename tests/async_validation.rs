@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use leptos::prelude::GetUntracked;
+use leptos::task::Executor;
+use urlap::{AsyncValidator, FieldError, FieldValue, Form, FormErrors, FormStruct, FormValidator};
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct SignUp {
+    username: String,
+}
+
+/// Builds a form whose username is checked by an [`AsyncValidator`]
+/// rejecting `taken`, counting how many times the check ran
+fn form(runs: Arc<AtomicUsize>) -> Form<SignUp, impl FormValidator<SignUp>> {
+    let _ = Executor::init_futures_executor();
+
+    let validator = AsyncValidator::new(move |value: FieldValue| {
+        runs.fetch_add(1, Ordering::SeqCst);
+
+        async move {
+            match value {
+                FieldValue::Text(name) if name == "taken" => Err(FieldError::new("username_taken")),
+                _ => Ok(()),
+            }
+        }
+    });
+
+    Form::with_validator(|_: &SignUp| Ok::<_, FormErrors>(()))
+        .with_async_validator("username", validator)
+}
+
+#[test]
+fn checks_run_outside_the_browser() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let form = form(runs.clone());
+
+    form.set_field_value("username", Some("taken".into()));
+    assert!(form.validate_field("username"));
+    assert!(form.is_validating("username").get_untracked());
+
+    Executor::poll_local();
+
+    assert!(!form.is_validating("username").get_untracked());
+    assert_eq!(
+        form.errors_for("username").get_untracked(),
+        [FieldError::new("username_taken")]
+    );
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn results_are_cached_per_value() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let form = form(runs.clone());
+
+    for name in ["taken", "ada", "taken", "ada"] {
+        form.set_field_value("username", Some(name.into()));
+        form.validate_field("username");
+        Executor::poll_local();
+    }
+
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    assert!(form.errors_for("username").get_untracked().is_empty());
+
+    form.invalidate_async_cache("username");
+    form.validate_field("username");
+    Executor::poll_local();
+
+    assert_eq!(runs.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,199 @@
+use urlap::{FieldValue, FormStruct};
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Line {
+    sku: String,
+    qty: u32,
+}
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Order {
+    #[form(rename = "full_name")]
+    name: String,
+    #[form(skip)]
+    secret: String,
+    age: u32,
+    subscribed: bool,
+    nickname: Option<String>,
+    #[form(nested)]
+    shipping: Address,
+    #[form(nested)]
+    items: Vec<Line>,
+    #[form(list)]
+    tags: Vec<String>,
+    #[form(parse = "parse_cents", format = "format_cents")]
+    price: u64,
+}
+
+fn parse_cents(value: &str) -> Result<u64, String> {
+    let (units, cents) = value.split_once('.').unwrap_or((value, "0"));
+    let units: u64 = units
+        .parse()
+        .map_err(|_| format!("`{value}` is not a price"))?;
+    let cents: u64 = cents
+        .parse()
+        .map_err(|_| format!("`{value}` is not a price"))?;
+
+    Ok(units * 100 + cents)
+}
+
+fn format_cents(value: &u64) -> String {
+    format!("{}.{:02}", value / 100, value % 100)
+}
+
+fn order() -> Order {
+    Order {
+        items: vec![
+            Line {
+                sku: "A-1".into(),
+                qty: 1,
+            },
+            Line {
+                sku: "B-2".into(),
+                qty: 2,
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn renamed_field_is_exposed_under_its_new_name() {
+    let mut order = order();
+
+    order.set("full_name", "Ada");
+
+    assert_eq!(order.name, "Ada");
+    assert_eq!(order.get("full_name").as_deref(), Some("Ada"));
+    assert_eq!(order.get("name"), None);
+}
+
+#[test]
+fn skipped_field_is_not_exposed() {
+    let mut order = order();
+
+    order.set("secret", "hunter2");
+
+    assert_eq!(order.secret, "");
+    assert_eq!(order.get("secret"), None);
+    assert!(!order.field_names().contains(&"secret".to_string()));
+}
+
+#[test]
+fn typed_fields_are_parsed() {
+    let mut order = order();
+
+    assert!(order.try_set("age", "42").is_ok());
+    assert_eq!(order.age, 42);
+    assert_eq!(order.get_value("age"), Some(FieldValue::Integer(42)));
+
+    let err = order.try_set("age", "forty").unwrap_err();
+    assert_eq!(err.code(), "integer");
+    assert_eq!(order.age, 42);
+
+    assert!(
+        order
+            .set_value("subscribed", FieldValue::Bool(true))
+            .is_ok()
+    );
+    assert_eq!(order.get_value("subscribed"), Some(FieldValue::Bool(true)));
+
+    assert!(order.try_set("nickname", "").is_ok());
+    assert_eq!(order.nickname, None);
+    assert!(order.try_set("nickname", "ada").is_ok());
+    assert_eq!(order.nickname.as_deref(), Some("ada"));
+}
+
+#[test]
+fn parse_and_format_hooks_are_used() {
+    let mut order = order();
+
+    assert!(order.try_set("price", "12.50").is_ok());
+    assert_eq!(order.price, 1250);
+    assert_eq!(order.get("price").as_deref(), Some("12.50"));
+
+    let err = order.try_set("price", "cheap").unwrap_err();
+    assert_eq!(err.code(), "parse");
+    assert_eq!(err.message(), "`cheap` is not a price");
+    assert_eq!(order.price, 1250);
+}
+
+#[test]
+fn nested_fields_are_accessed_by_path() {
+    let mut order = order();
+
+    assert!(order.try_set("shipping.city", "Oslo").is_ok());
+    assert_eq!(order.shipping.city, "Oslo");
+    assert_eq!(
+        order.get_value("shipping.city"),
+        Some(FieldValue::Text("Oslo".into()))
+    );
+    assert!(order.try_set("shipping.zip", "zip").is_err());
+    assert_eq!(order.get_value("shipping.missing"), None);
+}
+
+#[test]
+fn nested_list_rows_are_accessed_by_index() {
+    let mut order = order();
+
+    assert_eq!(
+        order.get_value("items[1].qty"),
+        Some(FieldValue::Integer(2))
+    );
+    assert!(
+        order
+            .set_value("items[0].sku", FieldValue::Text("C-3".into()))
+            .is_ok()
+    );
+    assert_eq!(order.items[0].sku, "C-3");
+    assert_eq!(order.get_value("items[2].qty"), None);
+}
+
+#[test]
+fn field_names_include_nested_paths() {
+    let names = order().field_names();
+
+    for name in [
+        "full_name",
+        "age",
+        "subscribed",
+        "shipping.city",
+        "shipping.zip",
+        "items[0].sku",
+        "items[1].qty",
+        "tags",
+        "price",
+    ] {
+        assert!(names.contains(&name.to_string()), "missing `{name}`");
+    }
+}
+
+#[test]
+fn list_fields_are_exposed_as_lists() {
+    let mut order = order();
+
+    assert!(
+        order
+            .set_value(
+                "tags",
+                FieldValue::List(vec![
+                    FieldValue::Text("new".into()),
+                    FieldValue::Text("gift".into()),
+                ])
+            )
+            .is_ok()
+    );
+    assert_eq!(order.tags, ["new", "gift"]);
+    assert_eq!(order.list("tags").map(|list| list.len()), Some(2));
+
+    order.list_mut("tags").unwrap().remove(0);
+    assert_eq!(order.tags, ["gift"]);
+    assert_eq!(order.list("items").map(|list| list.len()), Some(2));
+    assert!(order.list("age").is_none());
+}
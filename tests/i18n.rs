@@ -0,0 +1,51 @@
+use leptos::prelude::{GetUntracked, RwSignal, Set};
+use urlap::{FieldError, Form, FormErrors, FormStruct, FormValidator};
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Profile {
+    name: String,
+}
+
+fn translate(locale: &str, err: &FieldError) -> Option<String> {
+    match (locale, err.code.as_ref()) {
+        ("fr", "required") => Some("obligatoire".to_string()),
+        ("de", "required") => Some("erforderlich".to_string()),
+        _ => None,
+    }
+}
+
+fn form(locale: RwSignal<String>) -> Form<Profile, impl FormValidator<Profile>> {
+    Form::with_validator(|profile: &Profile| match profile.name.is_empty() {
+        true => Err(FormErrors::new().with_field_error("name", FieldError::new("required"))),
+        false => Ok(()),
+    })
+    .with_translator(translate, locale)
+}
+
+#[test]
+fn messages_follow_the_locale() {
+    let locale = RwSignal::new("fr".to_string());
+    let form = form(locale);
+    let error = form.error("name");
+    let messages = form.error_messages("name");
+
+    form.validate_field("name");
+    assert_eq!(error.get_untracked().as_deref(), Some("obligatoire"));
+
+    locale.set("de".to_string());
+    assert_eq!(error.get_untracked().as_deref(), Some("erforderlich"));
+    assert_eq!(messages.get_untracked(), ["erforderlich"]);
+}
+
+#[test]
+fn untranslated_messages_fall_back_to_the_resolver() {
+    let locale = RwSignal::new("en".to_string());
+    let form = form(locale);
+    let error = form.error("name");
+
+    form.validate_field("name");
+    assert_eq!(error.get_untracked().as_deref(), Some("is required"));
+
+    locale.set("fr".to_string());
+    assert_eq!(error.get_untracked().as_deref(), Some("obligatoire"));
+}
@@ -0,0 +1,102 @@
+use leptos::prelude::GetUntracked;
+use urlap::{Form, FormErrors, FormStruct, FormValidator, ResetOptions};
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Line {
+    sku: String,
+    qty: u32,
+}
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Order {
+    note: String,
+    #[form(nested)]
+    shipping: Address,
+    #[form(nested)]
+    items: Vec<Line>,
+    #[form(list)]
+    tags: Vec<String>,
+}
+
+fn form() -> Form<Order, impl FormValidator<Order>> {
+    let order = Order {
+        note: "leave at the door".into(),
+        shipping: Address {
+            city: "Oslo".into(),
+            zip: "0150".into(),
+        },
+        items: (0..3)
+            .map(|idx| Line {
+                sku: format!("SKU-{idx}"),
+                qty: 1,
+            })
+            .collect(),
+        tags: vec!["gift".into()],
+    };
+
+    Form::with_validator_and_values(|_: &Order| Ok::<_, FormErrors>(()), order)
+}
+
+#[test]
+fn reset_field_restores_list_rows_and_keys() {
+    let form = form();
+    let items = form.field_array::<Line>("items");
+    let keys = items.fields().get_untracked();
+
+    items.append(Line::default());
+    items.remove(0);
+    form.set_error("items[3].sku", "required");
+    form.set_field_value("items[1].qty", Some("5".into()));
+
+    form.reset_field("items", ResetOptions::new());
+
+    let fields = items.fields().get_untracked();
+    assert_eq!(fields.len(), 3);
+    assert!(fields.iter().all(|item| !keys.contains(item)));
+    assert_eq!(
+        form.value("items[0].sku").get_untracked(),
+        "SKU-0".to_string()
+    );
+    assert_eq!(form.value("items[1].qty").get_untracked(), "1".to_string());
+    assert!(form.errors().get_untracked().is_empty());
+    assert!(form.dirty_fields().get_untracked().is_empty());
+}
+
+#[test]
+fn reset_field_restores_nested_values() {
+    let form = form();
+
+    form.set_field_value("shipping.city", Some("Bergen".into()));
+    form.set_field_value("shipping.zip", Some("5003".into()));
+    form.set_field_value("note", Some("ring twice".into()));
+
+    form.reset_field("shipping", ResetOptions::new());
+
+    assert_eq!(form.value("shipping.city").get_untracked(), "Oslo");
+    assert_eq!(form.value("shipping.zip").get_untracked(), "0150");
+    assert_eq!(form.value("note").get_untracked(), "ring twice");
+    assert_eq!(form.dirty_fields().get_untracked(), ["note"]);
+}
+
+#[test]
+fn reset_field_keeps_errors_when_asked() {
+    let form = form();
+    let tags = form.field_array::<String>("tags");
+
+    tags.append("fragile".into());
+    form.set_error("tags", "too many tags");
+
+    form.reset_field("tags", ResetOptions::new().keep_errors());
+
+    assert_eq!(tags.len().get_untracked(), 1);
+    assert_eq!(
+        form.error("tags").get_untracked().as_deref(),
+        Some("too many tags")
+    );
+}
@@ -0,0 +1,98 @@
+use leptos::prelude::GetUntracked;
+use urlap::{FieldError, Form, FormErrors, FormStruct, FormValidator};
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, FormStruct)]
+struct Line {
+    qty: u32,
+}
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Order {
+    note: String,
+    #[form(nested)]
+    shipping: Address,
+    #[form(nested)]
+    items: Vec<Line>,
+}
+
+fn validate(order: &Order) -> Result<(), FormErrors> {
+    let mut errors = FormErrors::new();
+
+    if order.note.is_empty() {
+        errors.push_field_error("note", FieldError::new("required"));
+    }
+
+    if order.shipping.city.is_empty() {
+        errors.push_field_error("shipping.city", FieldError::new("required"));
+    }
+
+    for (idx, line) in order.items.iter().enumerate() {
+        if line.qty == 0 {
+            errors.push_field_error(format!("items[{idx}].qty"), FieldError::new("range"));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn form() -> Form<Order, impl FormValidator<Order>> {
+    let order = Order {
+        items: vec![Line { qty: 1 }, Line { qty: 0 }],
+        ..Default::default()
+    };
+
+    Form::with_validator_and_values(validate, order)
+}
+
+fn error_fields(form: &Form<Order, impl FormValidator<Order>>) -> Vec<String> {
+    let mut fields: Vec<String> = form.errors().get_untracked().fields.into_keys().collect();
+
+    fields.sort();
+    fields
+}
+
+#[test]
+fn trigger_validates_nested_fields() {
+    let form = form();
+
+    assert!(!form.trigger(&["shipping"]));
+    assert_eq!(error_fields(&form), ["shipping.city"]);
+
+    assert!(!form.trigger(&["items"]));
+    assert_eq!(error_fields(&form), ["items[1].qty", "shipping.city"]);
+}
+
+#[test]
+fn trigger_clears_fixed_nested_fields() {
+    let form = form();
+
+    form.trigger(&["shipping", "items"]);
+    form.set_field_value("shipping.city", Some("Oslo".into()));
+    form.set_error("shipping.city", "stale");
+    form.set_field_value("items[1].qty", Some("2".into()));
+    form.set_error("items[1].qty", "stale");
+
+    assert!(form.trigger(&["shipping", "items"]));
+    assert!(error_fields(&form).is_empty());
+}
+
+#[test]
+fn trigger_leaves_sibling_fields_alone() {
+    let form = form();
+
+    form.set_error("shipping_note", "too long");
+
+    assert!(!form.trigger(&["shipping"]));
+    assert_eq!(error_fields(&form), ["shipping.city", "shipping_note"]);
+    assert!(form.validate_field("shipping.zip"));
+}
@@ -0,0 +1,148 @@
+use leptos::prelude::GetUntracked;
+use urlap::{FieldError, Form, FormErrors, FormStruct};
+
+/// Form checked by a plain function, which keeps its type nameable
+type LoginForm = Form<Login, fn(&Login) -> Result<(), FormErrors>>;
+
+#[derive(Clone, Debug, Default, FormStruct)]
+struct Login {
+    email: String,
+    password: String,
+}
+
+fn check_login(login: &Login) -> Result<(), FormErrors> {
+    let mut errors = FormErrors::new();
+
+    if !login.email.contains('@') {
+        errors.push_field_error("email", FieldError::new("email"));
+    }
+
+    if login.password.len() < 8 {
+        errors.push_field_error("password", FieldError::new("length").with_param("min", 8));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[test]
+fn closure_validator_checks_the_form() {
+    let form: LoginForm = Form::with_validator(check_login);
+
+    form.set_field_value("email", Some("ada".into()));
+    assert!(!form.validate_field("email"));
+    assert_eq!(
+        form.error("email").get_untracked().as_deref(),
+        Some("must be a valid email address")
+    );
+    assert_eq!(form.error("password").get_untracked(), None);
+
+    assert!(!form.validate_field("password"));
+    assert_eq!(
+        form.error("password").get_untracked().as_deref(),
+        Some("must be at least 8 characters")
+    );
+
+    form.set_field_value("email", Some("ada@example.com".into()));
+    form.set_field_value("password", Some("correct horse".into()));
+    assert!(form.trigger(&["email", "password"]));
+    assert!(form.state().is_valid.get_untracked());
+}
+
+#[test]
+fn capturing_closure_validator_checks_the_form() {
+    let blocked = String::from("mallory@example.com");
+    let form = Form::with_validator_and_values(
+        move |login: &Login| match login.email == blocked {
+            true => Err(FormErrors::new().with_form_error(FieldError::new("blocked"))),
+            false => Ok(()),
+        },
+        Login {
+            email: "mallory@example.com".into(),
+            password: String::new(),
+        },
+    );
+
+    assert!(!form.state().is_valid.get_untracked());
+
+    form.set_field_value("email", Some("ada@example.com".into()));
+    assert!(form.state().is_valid.get_untracked());
+}
+
+#[cfg(feature = "garde")]
+mod garde_adapter {
+    use garde::Validate;
+    use urlap::{FormValidator, GardeAdapter};
+
+    #[derive(Clone, Debug, Validate)]
+    struct Line {
+        #[garde(range(min = 1))]
+        qty: u32,
+    }
+
+    #[derive(Clone, Debug, Validate)]
+    #[garde(custom(same_totals))]
+    struct Order {
+        #[garde(length(min = 3))]
+        name: String,
+        #[garde(dive)]
+        items: Vec<Line>,
+        #[garde(skip)]
+        total: u32,
+    }
+
+    fn same_totals(order: &Order, _: &()) -> garde::Result {
+        let total: u32 = order.items.iter().map(|line| line.qty).sum();
+
+        match total == order.total {
+            true => Ok(()),
+            false => Err(garde::Error::new("total does not match the lines")),
+        }
+    }
+
+    #[test]
+    fn garde_errors_are_mapped_to_field_paths() {
+        let order = Order {
+            name: "ab".into(),
+            items: vec![Line { qty: 1 }, Line { qty: 2 }, Line { qty: 0 }],
+            total: 3,
+        };
+
+        let errors = GardeAdapter.validate(&order).unwrap_err();
+
+        assert_eq!(errors.fields.len(), 2);
+        assert_eq!(errors.field("name").unwrap().first().unwrap().code, "garde");
+        assert_eq!(
+            errors
+                .field("items[2].qty")
+                .unwrap()
+                .first()
+                .unwrap()
+                .message
+                .as_deref(),
+            Some("lower than 1")
+        );
+        assert!(errors.form.is_empty());
+    }
+
+    #[test]
+    fn garde_errors_of_the_value_are_form_level() {
+        let order = Order {
+            name: "abc".into(),
+            items: vec![Line { qty: 1 }],
+            total: 5,
+        };
+
+        let errors = GardeAdapter.validate(&order).unwrap_err();
+
+        assert!(errors.fields.is_empty());
+        assert_eq!(
+            errors.form.first().unwrap().message.as_deref(),
+            Some("total does not match the lines")
+        );
+        assert!(GardeAdapter.validate(&Order { total: 1, ..order }).is_ok());
+    }
+}